@@ -1,6 +1,60 @@
 use std::time::Instant;
 use rand::prelude::*;
 
+/// A log-spaced latency histogram: `NUM_BUCKETS` edges spanning `MIN_SECONDS`
+/// to `MAX_SECONDS` on a log scale, so a handful of buckets covers latencies
+/// from microseconds to seconds without needing to know the scale in
+/// advance. Recording a duration increments the first bucket whose upper
+/// edge is `>=` it; percentiles walk the cumulative counts until the target
+/// fraction is reached.
+struct LatencyHistogram {
+    edges: Vec<f64>,
+    counts: Vec<u64>,
+}
+
+impl LatencyHistogram {
+    const NUM_BUCKETS: usize = 128;
+    const MIN_SECONDS: f64 = 1e-6;
+    const MAX_SECONDS: f64 = 10.0;
+
+    fn new() -> Self {
+        let n = Self::NUM_BUCKETS;
+        let ratio = Self::MAX_SECONDS / Self::MIN_SECONDS;
+        let edges = (0..n)
+            .map(|i| Self::MIN_SECONDS * ratio.powf(i as f64 / (n - 1) as f64))
+            .collect();
+        LatencyHistogram { edges, counts: vec![0; n] }
+    }
+
+    fn record(&mut self, seconds: f64) {
+        let bucket = match self.edges.binary_search_by(|edge| edge.partial_cmp(&seconds).unwrap()) {
+            Ok(idx) => idx,
+            Err(idx) => idx,
+        };
+        let bucket = bucket.min(self.counts.len() - 1);
+        self.counts[bucket] += 1;
+    }
+
+    /// Walks cumulative bucket counts until the cumulative fraction reaches
+    /// `p` (e.g. `0.99` for p99), returning that bucket's upper edge in
+    /// microseconds.
+    fn percentile_us(&self, p: f64) -> f64 {
+        let total: u64 = self.counts.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let target = (p * total as f64).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (i, &count) in self.counts.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                return self.edges[i] * 1_000_000.0;
+            }
+        }
+        self.edges[self.edges.len() - 1] * 1_000_000.0
+    }
+}
+
 fn binary_search(arr: &[i32], target: i32) -> Option<usize> {
     let mut left = 0;
     let mut right = arr.len();
@@ -31,14 +85,25 @@ fn main() {
     
     println!("Performing {} binary searches on array of size {}...", num_searches, size);
     let start = Instant::now();
-    
-    let found_count = targets
-        .iter()
-        .filter(|&&target| binary_search(&arr, target).is_some())
-        .count();
-    
+
+    let mut histogram = LatencyHistogram::new();
+    let mut found_count = 0;
+    for &target in &targets {
+        let search_start = Instant::now();
+        let found = binary_search(&arr, target).is_some();
+        histogram.record(search_start.elapsed().as_secs_f64());
+        if found {
+            found_count += 1;
+        }
+    }
+
     let duration = start.elapsed();
-    
+
     println!("Result: Found {}/{} targets", found_count, num_searches);
     println!("Execution time: {:.6} seconds", duration.as_secs_f64());
+    println!("Latency percentiles (us): p50={:.3} p90={:.3} p99={:.3} p999={:.3}",
+        histogram.percentile_us(0.50),
+        histogram.percentile_us(0.90),
+        histogram.percentile_us(0.99),
+        histogram.percentile_us(0.999));
 }
\ No newline at end of file
@@ -0,0 +1,346 @@
+/// Graph pathfinding benchmark implementation in Rust.
+/// Builds a deterministic weighted k-nearest-neighbor graph over 3D points
+/// and times A* shortest-path queries against it, optionally comparing a
+/// beam-bounded variant against the exact result.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+use std::env;
+use std::fs;
+use std::time::Instant;
+use serde::{Deserialize, Serialize};
+use serde_json;
+
+#[derive(Deserialize)]
+struct Config {
+    parameters: Parameters,
+}
+
+#[derive(Deserialize)]
+struct Parameters {
+    node_count: Option<usize>,
+    k_neighbors: Option<usize>,
+    num_queries: Option<usize>,
+    coordinate_range: Option<f64>,
+    beam_width: Option<usize>,
+}
+
+#[derive(Clone, Copy)]
+struct Point3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+fn euclidean(a: &Point3, b: &Point3) -> f64 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    let dz = a.z - b.z;
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+/// Same linear congruential generator as `shuffle_values` elsewhere in this
+/// suite, reused here so the generated graph (and the queries run against
+/// it) are reproducible across runs and languages.
+fn next_lcg(seed: &mut u32) -> u32 {
+    *seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+    *seed
+}
+
+fn generate_nodes(count: usize, range: f64) -> Vec<Point3> {
+    let mut seed = 42u32;
+    (0..count)
+        .map(|_| {
+            let x = (next_lcg(&mut seed) as f64 / u32::MAX as f64) * range;
+            let y = (next_lcg(&mut seed) as f64 / u32::MAX as f64) * range;
+            let z = (next_lcg(&mut seed) as f64 / u32::MAX as f64) * range;
+            Point3 { x, y, z }
+        })
+        .collect()
+}
+
+/// Connects every node to its `k` nearest neighbors by Euclidean distance,
+/// symmetrizing each edge so the resulting graph stays well-connected enough
+/// for A* queries to usually find a path.
+fn build_knn_graph(nodes: &[Point3], k: usize) -> Vec<Vec<(usize, f64)>> {
+    let n = nodes.len();
+    let mut graph: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+
+    for i in 0..n {
+        let mut distances: Vec<(usize, f64)> = (0..n)
+            .filter(|&j| j != i)
+            .map(|j| (j, euclidean(&nodes[i], &nodes[j])))
+            .collect();
+        distances.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+        for &(j, dist) in distances.iter().take(k) {
+            graph[i].push((j, dist));
+            graph[j].push((i, dist));
+        }
+    }
+
+    for adjacency in graph.iter_mut() {
+        adjacency.sort_by(|a, b| a.0.cmp(&b.0));
+        adjacency.dedup_by(|a, b| a.0 == b.0);
+    }
+
+    graph
+}
+
+/// A frontier entry keyed by `f = g + h`. `BinaryHeap` is a max-heap, so
+/// `Ord` is implemented in reverse of the natural `f64` order to make `pop`
+/// return the lowest-`f` entry, the standard trick for a min-heap in Rust.
+struct FrontierEntry {
+    f: f64,
+    node: usize,
+}
+
+impl PartialEq for FrontierEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.f == other.f
+    }
+}
+impl Eq for FrontierEntry {}
+impl PartialOrd for FrontierEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for FrontierEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f.partial_cmp(&self.f).unwrap_or(Ordering::Equal)
+    }
+}
+
+struct AstarOutcome {
+    found: bool,
+    nodes_expanded: usize,
+    path_length: usize,
+    path_cost: f64,
+}
+
+/// A* search from `start` to `goal`. When `beam_width` is `Some(bw)`, the
+/// frontier is trimmed to its `bw` lowest-`f` entries after every expansion,
+/// bounding memory at the cost of optimality (the search may miss a cheaper
+/// path pruned off the frontier before it was reached).
+fn astar(nodes: &[Point3], graph: &[Vec<(usize, f64)>], start: usize, goal: usize, beam_width: Option<usize>) -> AstarOutcome {
+    let n = nodes.len();
+    let mut g_score = vec![f64::INFINITY; n];
+    let mut closed: HashSet<usize> = HashSet::new();
+    let mut open: BinaryHeap<FrontierEntry> = BinaryHeap::new();
+
+    g_score[start] = 0.0;
+    open.push(FrontierEntry { f: euclidean(&nodes[start], &nodes[goal]), node: start });
+
+    let mut came_from = vec![usize::MAX; n];
+    let mut nodes_expanded = 0;
+    let mut found = false;
+
+    while let Some(current) = open.pop() {
+        if closed.contains(&current.node) {
+            continue;
+        }
+        closed.insert(current.node);
+        nodes_expanded += 1;
+
+        if current.node == goal {
+            found = true;
+            break;
+        }
+
+        for &(neighbor, weight) in &graph[current.node] {
+            if closed.contains(&neighbor) {
+                continue;
+            }
+            let tentative_g = g_score[current.node] + weight;
+            if tentative_g < g_score[neighbor] {
+                g_score[neighbor] = tentative_g;
+                came_from[neighbor] = current.node;
+                let f = tentative_g + euclidean(&nodes[neighbor], &nodes[goal]);
+                open.push(FrontierEntry { f, node: neighbor });
+            }
+        }
+
+        if let Some(bw) = beam_width {
+            if open.len() > bw {
+                let mut entries: Vec<FrontierEntry> = open.drain().collect();
+                entries.sort_by(|a, b| a.f.partial_cmp(&b.f).unwrap());
+                entries.truncate(bw);
+                open = entries.into_iter().collect();
+            }
+        }
+    }
+
+    let path_length = if found {
+        let mut len = 1;
+        let mut node = goal;
+        while node != start {
+            node = came_from[node];
+            len += 1;
+        }
+        len
+    } else {
+        0
+    };
+
+    AstarOutcome {
+        found,
+        nodes_expanded,
+        path_length,
+        path_cost: if found { g_score[goal] } else { f64::INFINITY },
+    }
+}
+
+#[derive(Serialize)]
+struct QueryResult {
+    start: usize,
+    goal: usize,
+    found: bool,
+    nodes_expanded: usize,
+    path_length: usize,
+    path_cost: f64,
+    query_time_ms: f64,
+    beam_nodes_expanded: Option<usize>,
+    beam_path_cost: Option<f64>,
+    beam_suboptimal: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct GraphStats {
+    node_count: usize,
+    edge_count: usize,
+    k_neighbors: usize,
+}
+
+#[derive(Serialize)]
+struct Summary {
+    queries_run: usize,
+    paths_found: usize,
+    avg_nodes_expanded: f64,
+    avg_path_cost: f64,
+    avg_query_time_ms: f64,
+    beam_suboptimal_count: usize,
+}
+
+#[derive(Serialize)]
+struct BenchmarkResult {
+    start_time: f64,
+    graph_stats: GraphStats,
+    queries: Vec<QueryResult>,
+    summary: Summary,
+    end_time: f64,
+    total_execution_time: f64,
+}
+
+fn run_benchmark(parameters: Parameters) -> BenchmarkResult {
+    let start_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64();
+
+    let node_count = parameters.node_count.unwrap_or(500).max(2);
+    let k_neighbors = parameters.k_neighbors.unwrap_or(8).min(node_count - 1);
+    let num_queries = parameters.num_queries.unwrap_or(20);
+    let coordinate_range = parameters.coordinate_range.unwrap_or(1000.0);
+    let beam_width = parameters.beam_width;
+
+    let nodes = generate_nodes(node_count, coordinate_range);
+    let graph = build_knn_graph(&nodes, k_neighbors);
+    let edge_count: usize = graph.iter().map(|adj| adj.len()).sum::<usize>() / 2;
+
+    let mut seed = 1337u32;
+    let mut queries = Vec::with_capacity(num_queries);
+    let mut nodes_expanded_total = 0usize;
+    let mut path_cost_total = 0.0;
+    let mut query_time_total_ms = 0.0;
+    let mut paths_found = 0usize;
+    let mut beam_suboptimal_count = 0usize;
+
+    for _ in 0..num_queries {
+        let start = (next_lcg(&mut seed) as usize) % node_count;
+        let mut goal = (next_lcg(&mut seed) as usize) % node_count;
+        if goal == start {
+            goal = (goal + 1) % node_count;
+        }
+
+        let query_start = Instant::now();
+        let exact = astar(&nodes, &graph, start, goal, None);
+        let beam_outcome = beam_width.map(|bw| astar(&nodes, &graph, start, goal, Some(bw)));
+        let query_time_ms = query_start.elapsed().as_secs_f64() * 1000.0;
+
+        let beam_suboptimal = beam_outcome.as_ref().map(|beam| {
+            let suboptimal = !beam.found || beam.path_cost > exact.path_cost + 1e-9;
+            if suboptimal {
+                beam_suboptimal_count += 1;
+            }
+            suboptimal
+        });
+
+        nodes_expanded_total += exact.nodes_expanded;
+        query_time_total_ms += query_time_ms;
+        if exact.found {
+            paths_found += 1;
+            path_cost_total += exact.path_cost;
+        }
+
+        queries.push(QueryResult {
+            start,
+            goal,
+            found: exact.found,
+            nodes_expanded: exact.nodes_expanded,
+            path_length: exact.path_length,
+            path_cost: exact.path_cost,
+            query_time_ms,
+            beam_nodes_expanded: beam_outcome.as_ref().map(|b| b.nodes_expanded),
+            beam_path_cost: beam_outcome.as_ref().map(|b| b.path_cost),
+            beam_suboptimal,
+        });
+    }
+
+    let summary = Summary {
+        queries_run: queries.len(),
+        paths_found,
+        avg_nodes_expanded: if !queries.is_empty() { nodes_expanded_total as f64 / queries.len() as f64 } else { 0.0 },
+        avg_path_cost: if paths_found > 0 { path_cost_total / paths_found as f64 } else { 0.0 },
+        avg_query_time_ms: if !queries.is_empty() { query_time_total_ms / queries.len() as f64 } else { 0.0 },
+        beam_suboptimal_count,
+    };
+
+    let end_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64();
+
+    BenchmarkResult {
+        start_time,
+        graph_stats: GraphStats { node_count, edge_count, k_neighbors },
+        queries,
+        summary,
+        end_time,
+        total_execution_time: end_time - start_time,
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().collect();
+
+    let parameters = if args.len() > 1 {
+        let config_file = &args[1];
+        let config_content = fs::read_to_string(config_file)?;
+        let config: Config = serde_json::from_str(&config_content)?;
+        config.parameters
+    } else {
+        Parameters {
+            node_count: None,
+            k_neighbors: None,
+            num_queries: None,
+            coordinate_range: None,
+            beam_width: None,
+        }
+    };
+
+    let result = run_benchmark(parameters);
+    println!("{}", serde_json::to_string_pretty(&result)?);
+
+    Ok(())
+}
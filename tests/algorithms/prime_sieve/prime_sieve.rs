@@ -33,10 +33,16 @@ fn main() {
     let primes = sieve_of_eratosthenes(n);
     
     let duration = start.elapsed();
-    
+
     println!("Result: Found {} primes", primes.len());
     if let Some(&largest) = primes.last() {
         println!("Largest prime: {}", largest);
     }
     println!("Execution time: {:.6} seconds", duration.as_secs_f64());
+
+    let elapsed_secs = duration.as_secs_f64();
+    let numbers_per_sec = if elapsed_secs > 0.0 { n as f64 / elapsed_secs } else { 0.0 };
+    let primes_per_sec = if elapsed_secs > 0.0 { primes.len() as f64 / elapsed_secs } else { 0.0 };
+    println!("Numbers sieved/sec: {:.2}", numbers_per_sec);
+    println!("Primes/sec: {:.2}", primes_per_sec);
 }
\ No newline at end of file
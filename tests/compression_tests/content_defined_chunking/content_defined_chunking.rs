@@ -0,0 +1,514 @@
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::env;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::time::Instant;
+use serde::{Deserialize, Serialize};
+use serde_json;
+use rand::Rng;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+/// Rolling gear-hash chunker: at each byte, folds a per-byte random value
+/// into a 64-bit rolling hash (`hash = hash << 1 + table[byte]`) and cuts a
+/// chunk boundary whenever the hash bits selected by `mask` are all zero, the same
+/// approach used by real dedup/backup tools to find content-defined
+/// boundaries without needing a separator byte. Cuts are additionally
+/// bounded by `min_size`/`max_size` so pathological inputs can't produce
+/// degenerate (empty or unbounded) chunks.
+struct GearChunker {
+    table: [u64; 256],
+    mask: u64,
+    min_size: usize,
+    max_size: usize,
+}
+
+impl GearChunker {
+    /// `window_size` only perturbs the seed the gear table is generated
+    /// from; it does not change how many recent bytes the rolling hash
+    /// depends on. The table is generated once from that fixed seed so chunk
+    /// boundaries are reproducible across runs.
+    fn new(avg_size: usize, min_size: usize, max_size: usize, window_size: u32) -> Self {
+        let mut table = [0u64; 256];
+        let mut seed = 0x9E3779B97F4A7C15u64 ^ (window_size as u64);
+        for entry in table.iter_mut() {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            *entry = seed;
+        }
+
+        let bits = (avg_size.max(2) as f64).log2().round() as u32;
+        let mask = (1u64 << bits.min(63)) - 1;
+
+        GearChunker { table, mask, min_size, max_size }
+    }
+
+    /// Streams `data` through the rolling hash and records only chunk
+    /// `(offset, length)` pairs, so the measured cost is purely the
+    /// chunking work and never compression.
+    fn chunk(&self, data: &[u8]) -> Vec<(usize, usize)> {
+        let mut boundaries = Vec::new();
+        let mut hash: u64 = 0;
+        let mut chunk_start = 0usize;
+
+        for (i, &byte) in data.iter().enumerate() {
+            hash = (hash << 1).wrapping_add(self.table[byte as usize]);
+            let len = i + 1 - chunk_start;
+
+            if len >= self.max_size || (len >= self.min_size && (hash & self.mask) == 0) {
+                boundaries.push((chunk_start, len));
+                chunk_start = i + 1;
+                hash = 0;
+            }
+        }
+
+        if chunk_start < data.len() {
+            boundaries.push((chunk_start, data.len() - chunk_start));
+        }
+
+        boundaries
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ChunkDistribution {
+    chunk_count: usize,
+    mean_chunk_size: f64,
+    stddev_chunk_size: f64,
+    min_chunk_size: usize,
+    max_chunk_size: usize,
+}
+
+/// Deduplication stats over one test case's chunks, identified by a
+/// content hash (not cryptographic - `DefaultHasher` is fast and collision
+/// risk at benchmark data volumes is negligible, same trade real
+/// high-throughput dedup systems make with short hashes plus a verify
+/// step). `compressed_unique_bytes`/`combined_ratio` are only populated
+/// when `compress_unique_chunks` is enabled.
+#[derive(Debug, Serialize)]
+struct DedupStats {
+    total_chunks: usize,
+    unique_chunks: usize,
+    total_bytes: usize,
+    unique_bytes: usize,
+    dedup_ratio: f64,
+    compressed_unique_bytes: Option<usize>,
+    combined_ratio: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+struct TestCase {
+    input_size: usize,
+    data_type: String,
+    target_min_chunk_size: usize,
+    target_avg_chunk_size: usize,
+    target_max_chunk_size: usize,
+    chunking_time_ms: f64,
+    chunks_per_sec: f64,
+    throughput_mb_s: f64,
+    distribution: ChunkDistribution,
+    dedup: DedupStats,
+}
+
+#[derive(Debug, Serialize)]
+struct Summary {
+    total_tests: u32,
+    avg_chunking_time_ms: f64,
+    avg_chunks_per_sec: f64,
+    avg_throughput_mb_s: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct BenchmarkResults {
+    start_time: f64,
+    test_cases: Vec<TestCase>,
+    summary: Summary,
+    end_time: Option<f64>,
+    total_execution_time: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Config {
+    parameters: Parameters,
+}
+
+#[derive(Debug, Deserialize)]
+struct Parameters {
+    input_sizes: Option<Vec<usize>>,
+    data_types: Option<Vec<String>>,
+    min_chunk_size: Option<usize>,
+    avg_chunk_size: Option<usize>,
+    max_chunk_size: Option<usize>,
+    window_size: Option<u32>,
+    iterations: Option<u32>,
+    /// Real files to chunk instead of (or alongside) the synthetic
+    /// generators, labelled by basename. Each file is read once and
+    /// chunked as-is, so `input_sizes` doesn't apply to it.
+    input_files: Option<Vec<String>>,
+    /// Compress each deduplicated chunk with gzip to report a combined
+    /// dedup+compression ratio alongside the dedup-only one.
+    compress_unique_chunks: Option<bool>,
+}
+
+/// A labelled source of chunking input: synthetic (sized per
+/// `input_sizes`, regenerated every iteration) or a real file (read once,
+/// chunked identically every iteration).
+enum DataSource {
+    Synthetic(String),
+    File(String),
+}
+
+fn generate_test_data(size: usize, data_type: &str) -> Result<Vec<u8>, String> {
+    let mut rng = rand::thread_rng();
+
+    match data_type {
+        "text" => {
+            let chars = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789 \n";
+            let result: String = (0..size)
+                .map(|_| chars.chars().nth(rng.gen_range(0..chars.len())).unwrap())
+                .collect();
+            Ok(result.into_bytes())
+        }
+        "binary" => {
+            let result: Vec<u8> = (0..size).map(|_| rng.gen_range(0..256) as u8).collect();
+            Ok(result)
+        }
+        "repetitive" => {
+            // One random 256-byte block repeated to fill `size`, so the
+            // same content recurs throughout the input.
+            let block: Vec<u8> = (0..256).map(|_| rng.gen_range(0..256) as u8).collect();
+            let mut result = Vec::with_capacity(size);
+            while result.len() < size {
+                result.extend_from_slice(&block);
+            }
+            result.truncate(size);
+            Ok(result)
+        }
+        _ => Err(format!("Unknown data type: {}", data_type)),
+    }
+}
+
+fn mean_and_stddev(lengths: &[usize]) -> (f64, f64) {
+    if lengths.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = lengths.len() as f64;
+    let mean = lengths.iter().sum::<usize>() as f64 / n;
+    let variance = lengths
+        .iter()
+        .map(|&len| {
+            let diff = len as f64 - mean;
+            diff * diff
+        })
+        .sum::<f64>()
+        / n;
+    (mean, variance.sqrt())
+}
+
+fn compress_chunk_gzip(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+/// Deduplicates `boundaries`' chunks of `data` by content hash into a
+/// `HashSet`, optionally gzip-compressing each unique chunk to report a
+/// combined dedup+compression ratio.
+fn dedup_chunks(data: &[u8], boundaries: &[(usize, usize)], compress_unique: bool) -> DedupStats {
+    let mut seen = HashSet::new();
+    let mut unique_bytes = 0usize;
+    let mut compressed_unique_bytes = 0usize;
+    let total_bytes: usize = boundaries.iter().map(|&(_, len)| len).sum();
+
+    for &(start, len) in boundaries {
+        let chunk = &data[start..start + len];
+        let mut hasher = DefaultHasher::new();
+        chunk.hash(&mut hasher);
+        if seen.insert(hasher.finish()) {
+            unique_bytes += len;
+            if compress_unique {
+                if let Ok(compressed) = compress_chunk_gzip(chunk) {
+                    compressed_unique_bytes += compressed.len();
+                }
+            }
+        }
+    }
+
+    let dedup_ratio = if unique_bytes > 0 {
+        total_bytes as f64 / unique_bytes as f64
+    } else {
+        0.0
+    };
+
+    DedupStats {
+        total_chunks: boundaries.len(),
+        unique_chunks: seen.len(),
+        total_bytes,
+        unique_bytes,
+        dedup_ratio: (dedup_ratio * 1000.0).round() / 1000.0,
+        compressed_unique_bytes: if compress_unique { Some(compressed_unique_bytes) } else { None },
+        combined_ratio: if compress_unique && compressed_unique_bytes > 0 {
+            Some((total_bytes as f64 / compressed_unique_bytes as f64 * 1000.0).round() / 1000.0)
+        } else {
+            None
+        },
+    }
+}
+
+/// Runs all iterations for one `(label, size)` combination, pulling fresh
+/// test data from `get_data` each time (synthetic sources regenerate it;
+/// file sources just hand back the same bytes), and returns the averaged
+/// `TestCase` plus the raw per-iteration samples so the caller can roll
+/// them into the overall summary.
+fn run_one_test_case(
+    label: &str,
+    size: usize,
+    chunker: &GearChunker,
+    min_chunk_size: usize,
+    avg_chunk_size: usize,
+    max_chunk_size: usize,
+    iterations: u32,
+    compress_unique_chunks: bool,
+    get_data: impl Fn() -> Vec<u8>,
+) -> (TestCase, Vec<f64>, Vec<f64>, Vec<f64>) {
+    eprintln!("Testing {} data, size: {} bytes...", label, size);
+
+    let mut iteration_times = Vec::new();
+    let mut iteration_chunks_per_sec = Vec::new();
+    let mut iteration_throughputs = Vec::new();
+    let mut last_distribution = ChunkDistribution {
+        chunk_count: 0,
+        mean_chunk_size: 0.0,
+        stddev_chunk_size: 0.0,
+        min_chunk_size: 0,
+        max_chunk_size: 0,
+    };
+    let mut last_dedup = DedupStats {
+        total_chunks: 0,
+        unique_chunks: 0,
+        total_bytes: 0,
+        unique_bytes: 0,
+        dedup_ratio: 0.0,
+        compressed_unique_bytes: None,
+        combined_ratio: None,
+    };
+
+    for i in 0..iterations {
+        eprintln!("  Iteration {}/{}...", i + 1, iterations);
+
+        let test_data = get_data();
+
+        let start = Instant::now();
+        let boundaries = chunker.chunk(&test_data);
+        let elapsed = start.elapsed().as_secs_f64();
+
+        let lengths: Vec<usize> = boundaries.iter().map(|&(_, len)| len).collect();
+        let (mean, stddev) = mean_and_stddev(&lengths);
+        let chunks_per_sec = if elapsed > 0.0 { lengths.len() as f64 / elapsed } else { 0.0 };
+        let throughput_mb_s = if elapsed > 0.0 {
+            (test_data.len() as f64 / elapsed) / (1024.0 * 1024.0)
+        } else {
+            0.0
+        };
+
+        last_distribution = ChunkDistribution {
+            chunk_count: lengths.len(),
+            mean_chunk_size: (mean * 100.0).round() / 100.0,
+            stddev_chunk_size: (stddev * 100.0).round() / 100.0,
+            min_chunk_size: lengths.iter().copied().min().unwrap_or(0),
+            max_chunk_size: lengths.iter().copied().max().unwrap_or(0),
+        };
+        last_dedup = dedup_chunks(&test_data, &boundaries, compress_unique_chunks);
+
+        iteration_times.push(elapsed * 1000.0);
+        iteration_chunks_per_sec.push(chunks_per_sec);
+        iteration_throughputs.push(throughput_mb_s);
+    }
+
+    let avg_time = if !iteration_times.is_empty() {
+        iteration_times.iter().sum::<f64>() / iteration_times.len() as f64
+    } else {
+        0.0
+    };
+    let avg_chunks_per_sec = if !iteration_chunks_per_sec.is_empty() {
+        iteration_chunks_per_sec.iter().sum::<f64>() / iteration_chunks_per_sec.len() as f64
+    } else {
+        0.0
+    };
+    let avg_throughput = if !iteration_throughputs.is_empty() {
+        iteration_throughputs.iter().sum::<f64>() / iteration_throughputs.len() as f64
+    } else {
+        0.0
+    };
+
+    let test_case = TestCase {
+        input_size: size,
+        data_type: label.to_string(),
+        target_min_chunk_size: min_chunk_size,
+        target_avg_chunk_size: avg_chunk_size,
+        target_max_chunk_size: max_chunk_size,
+        chunking_time_ms: (avg_time * 100.0).round() / 100.0,
+        chunks_per_sec: (avg_chunks_per_sec * 100.0).round() / 100.0,
+        throughput_mb_s: (avg_throughput * 100.0).round() / 100.0,
+        distribution: last_distribution,
+        dedup: last_dedup,
+    };
+
+    (test_case, iteration_times, iteration_chunks_per_sec, iteration_throughputs)
+}
+
+fn run_chunking_benchmark(config: Parameters) -> BenchmarkResults {
+    let input_sizes = config.input_sizes.unwrap_or_else(|| vec![1_048_576]);
+    let min_chunk_size = config.min_chunk_size.unwrap_or(2 * 1024);
+    let avg_chunk_size = config.avg_chunk_size.unwrap_or(8 * 1024);
+    let max_chunk_size = config.max_chunk_size.unwrap_or(64 * 1024);
+    let window_size = config.window_size.unwrap_or(48);
+    let iterations = config.iterations.unwrap_or(5);
+    let compress_unique_chunks = config.compress_unique_chunks.unwrap_or(false);
+
+    // Synthetic types fall back to the "text" default only when there are
+    // no real files to test either, mirroring the compression benchmark's
+    // `input_files`-only convention.
+    let data_types: Vec<String> = match config.data_types {
+        Some(types) => types,
+        None if config.input_files.is_none() => vec!["text".to_string()],
+        None => Vec::new(),
+    };
+
+    let mut sources: Vec<DataSource> = data_types.into_iter().map(DataSource::Synthetic).collect();
+    if let Some(files) = &config.input_files {
+        sources.extend(files.iter().cloned().map(DataSource::File));
+    }
+
+    let chunker = GearChunker::new(avg_chunk_size, min_chunk_size, max_chunk_size, window_size);
+
+    let mut results = BenchmarkResults {
+        start_time: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs_f64(),
+        test_cases: Vec::new(),
+        summary: Summary {
+            total_tests: 0,
+            avg_chunking_time_ms: 0.0,
+            avg_chunks_per_sec: 0.0,
+            avg_throughput_mb_s: 0.0,
+        },
+        end_time: None,
+        total_execution_time: None,
+    };
+
+    let mut total_times = Vec::new();
+    let mut total_chunks_per_sec = Vec::new();
+    let mut total_throughputs = Vec::new();
+
+    for source in &sources {
+        match source {
+            DataSource::Synthetic(data_type) => {
+                for &size in &input_sizes {
+                    let (test_case, times, chunks_per_sec, throughputs) = run_one_test_case(
+                        data_type,
+                        size,
+                        &chunker,
+                        min_chunk_size,
+                        avg_chunk_size,
+                        max_chunk_size,
+                        iterations,
+                        compress_unique_chunks,
+                        || generate_test_data(size, data_type).unwrap_or_default(),
+                    );
+
+                    results.summary.total_tests += 1;
+                    results.test_cases.push(test_case);
+                    total_times.extend(times);
+                    total_chunks_per_sec.extend(chunks_per_sec);
+                    total_throughputs.extend(throughputs);
+                }
+            }
+            DataSource::File(path) => {
+                let file_bytes = match fs::read(path) {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        eprintln!("Warning: could not read input file {}: {}", path, e);
+                        continue;
+                    }
+                };
+                let label = std::path::Path::new(path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| path.clone());
+                let size = file_bytes.len();
+
+                let (test_case, times, chunks_per_sec, throughputs) = run_one_test_case(
+                    &label,
+                    size,
+                    &chunker,
+                    min_chunk_size,
+                    avg_chunk_size,
+                    max_chunk_size,
+                    iterations,
+                    compress_unique_chunks,
+                    || file_bytes.clone(),
+                );
+
+                results.summary.total_tests += 1;
+                results.test_cases.push(test_case);
+                total_times.extend(times);
+                total_chunks_per_sec.extend(chunks_per_sec);
+                total_throughputs.extend(throughputs);
+            }
+        }
+    }
+
+    if !total_times.is_empty() {
+        results.summary.avg_chunking_time_ms = total_times.iter().sum::<f64>() / total_times.len() as f64;
+        results.summary.avg_chunks_per_sec = total_chunks_per_sec.iter().sum::<f64>() / total_chunks_per_sec.len() as f64;
+        results.summary.avg_throughput_mb_s = total_throughputs.iter().sum::<f64>() / total_throughputs.len() as f64;
+    }
+
+    let end_time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64();
+    results.end_time = Some(end_time);
+    results.total_execution_time = Some(end_time - results.start_time);
+
+    results
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 {
+        eprintln!("Usage: {} <config_file>", args[0]);
+        std::process::exit(1);
+    }
+
+    let config_file = &args[1];
+
+    let config_content = match fs::read_to_string(config_file) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Error: Cannot read config file '{}': {}", config_file, e);
+            std::process::exit(1);
+        }
+    };
+
+    let config: Config = match serde_json::from_str(&config_content) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: Invalid JSON in config file: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let results = run_chunking_benchmark(config.parameters);
+
+    match serde_json::to_string_pretty(&results) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Error: Failed to serialize results: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::time::Instant;
@@ -8,6 +9,290 @@ use flate2::write::GzEncoder;
 use flate2::Compression;
 use std::io::Write;
 
+const FSST_ESCAPE: u8 = 255;
+const FSST_MAX_SYMBOLS: usize = 255;
+const FSST_MAX_SYMBOL_LEN: usize = 8;
+const FSST_TRAIN_ROUNDS: u32 = 5;
+const FSST_HASH_BITS: u32 = 13;
+const FSST_HASH_SIZE: usize = 1 << FSST_HASH_BITS;
+
+/// Hashes all of `bytes` (callers pass a 2- or 3-byte key) into a
+/// `FSST_HASH_SIZE`-slot index.
+fn fsst_hash(bytes: &[u8]) -> usize {
+    let mut h: u32 = 2166136261;
+    for &b in bytes {
+        h ^= b as u32;
+        h = h.wrapping_mul(16777619);
+    }
+    (h as usize) & (FSST_HASH_SIZE - 1)
+}
+
+/// A trained FSST (Fast Static Symbol Table) dictionary: up to 255 byte-string
+/// symbols (1-8 bytes each) indexed by their assigned code. Lookup uses
+/// "lossy" single-slot hash tables keyed on the first 2-3 bytes at the
+/// cursor: each slot holds at most one candidate symbol, so a hash
+/// collision is simply treated as "no match" at that length (the probe falls
+/// back to the next shorter length, and finally to the literal escape)
+/// rather than chained. This trades a little compression ratio for
+/// an O(1) longest-match probe instead of scanning every possible length;
+/// correctness is unaffected either way since decoding just expands
+/// whatever code was actually emitted.
+struct FsstTable {
+    symbols: Vec<Vec<u8>>,
+    // Length-1 symbols: direct 256-entry index, so single-byte fallbacks
+    // (which every trained table backfills) are never lost to a collision.
+    slot1: Vec<Option<(Vec<u8>, u8)>>,
+    // Length-2 symbols, keyed by a hash of their 2 bytes.
+    slot2: Vec<Option<(Vec<u8>, u8)>>,
+    // Length >= 3 symbols, keyed by a hash of their first 3 bytes; ties
+    // prefer the longer symbol since it compresses more per code.
+    slot3: Vec<Option<(Vec<u8>, u8)>>,
+}
+
+impl FsstTable {
+    fn longest_match(&self, data: &[u8]) -> Option<(u8, usize)> {
+        if data.len() >= 3 {
+            if let Some((sym, code)) = &self.slot3[fsst_hash(&data[..3])] {
+                if data.len() >= sym.len() && &data[..sym.len()] == sym.as_slice() {
+                    return Some((*code, sym.len()));
+                }
+            }
+        }
+        if data.len() >= 2 {
+            if let Some((sym, code)) = &self.slot2[fsst_hash(&data[..2])] {
+                if &data[..2] == sym.as_slice() {
+                    return Some((*code, 2));
+                }
+            }
+        }
+        if let Some((sym, code)) = &self.slot1[data[0] as usize] {
+            if &data[..1] == sym.as_slice() {
+                return Some((*code, 1));
+            }
+        }
+        None
+    }
+
+    /// Trains a table over `samples` by iterating a greedy scan-count-rank
+    /// loop: each round scans the corpus with the *current* table, tallies
+    /// how often each matched symbol (and each adjacent-pair concatenation)
+    /// occurs, then keeps the top symbols ranked by `count * length`,
+    /// backfilling any unused codes with single-byte fallbacks until the
+    /// table holds `FSST_MAX_SYMBOLS` symbols.
+    fn train(samples: &[&[u8]]) -> Self {
+        let mut table = FsstTable::from_symbols(Vec::new());
+
+        for _ in 0..FSST_TRAIN_ROUNDS {
+            let mut counts: HashMap<Vec<u8>, usize> = HashMap::new();
+
+            for sample in samples {
+                let mut pos = 0;
+                while pos < sample.len() {
+                    let (sym1, len1) = match table.longest_match(&sample[pos..]) {
+                        Some((_, len)) => (sample[pos..pos + len].to_vec(), len),
+                        None => (vec![sample[pos]], 1),
+                    };
+                    *counts.entry(sym1.clone()).or_insert(0) += 1;
+
+                    if pos + len1 < sample.len() {
+                        let (sym2, _len2) = match table.longest_match(&sample[pos + len1..]) {
+                            Some((_, len)) => (sample[pos + len1..pos + len1 + len].to_vec(), len),
+                            None => (vec![sample[pos + len1]], 1),
+                        };
+                        if sym1.len() + sym2.len() <= FSST_MAX_SYMBOL_LEN {
+                            let mut concat = sym1.clone();
+                            concat.extend_from_slice(&sym2);
+                            *counts.entry(concat).or_insert(0) += 1;
+                        }
+                    }
+
+                    pos += len1;
+                }
+            }
+
+            let mut ranked: Vec<(Vec<u8>, usize)> = counts.into_iter().collect();
+            ranked.sort_by(|a, b| {
+                let gain_a = a.1 * a.0.len();
+                let gain_b = b.1 * b.0.len();
+                gain_b.cmp(&gain_a)
+            });
+
+            let mut next_symbols: Vec<Vec<u8>> = Vec::with_capacity(FSST_MAX_SYMBOLS);
+            let mut seen: std::collections::HashSet<Vec<u8>> = std::collections::HashSet::new();
+            for (sym, _) in ranked {
+                if next_symbols.len() >= FSST_MAX_SYMBOLS {
+                    break;
+                }
+                if seen.insert(sym.clone()) {
+                    next_symbols.push(sym);
+                }
+            }
+            // Backfill with single-byte fallbacks, in byte order, until the
+            // table is full; a byte left without a symbol is still encoded
+            // through the escape code.
+            for byte in 0u16..256 {
+                if next_symbols.len() >= FSST_MAX_SYMBOLS {
+                    break;
+                }
+                let candidate = vec![byte as u8];
+                if seen.insert(candidate.clone()) {
+                    next_symbols.push(candidate);
+                }
+            }
+
+            table = FsstTable::from_symbols(next_symbols);
+        }
+
+        table
+    }
+
+    /// Trains one shared table across many short strings (e.g. individual
+    /// lines/records) rather than a single large blob, so the resulting
+    /// dictionary supports compressing each string independently for
+    /// random access - the same `train` loop handles multiple samples, so
+    /// this is just the name callers reach for in that use case.
+    fn train_bulk(samples: &[&[u8]]) -> Self {
+        Self::train(samples)
+    }
+
+    fn from_symbols(symbols: Vec<Vec<u8>>) -> Self {
+        let mut slot1: Vec<Option<(Vec<u8>, u8)>> = vec![None; 256];
+        let mut slot2: Vec<Option<(Vec<u8>, u8)>> = vec![None; FSST_HASH_SIZE];
+        let mut slot3: Vec<Option<(Vec<u8>, u8)>> = vec![None; FSST_HASH_SIZE];
+
+        for (code, symbol) in symbols.iter().enumerate() {
+            let code = code as u8;
+            match symbol.len() {
+                1 => slot1[symbol[0] as usize] = Some((symbol.clone(), code)),
+                2 => {
+                    let key = fsst_hash(symbol);
+                    let keep_existing = slot2[key].as_ref().is_some_and(|(existing, _)| existing.len() >= symbol.len());
+                    if !keep_existing {
+                        slot2[key] = Some((symbol.clone(), code));
+                    }
+                }
+                _ => {
+                    let key = fsst_hash(&symbol[..3]);
+                    let keep_existing = slot3[key].as_ref().is_some_and(|(existing, _)| existing.len() >= symbol.len());
+                    if !keep_existing {
+                        slot3[key] = Some((symbol.clone(), code));
+                    }
+                }
+            }
+        }
+
+        FsstTable { symbols, slot1, slot2, slot3 }
+    }
+
+    /// Compresses `data`, returning the encoded bytes alongside the number
+    /// of codes emitted (each either a dictionary symbol or an
+    /// escape+literal pair), so callers can report bytes-per-symbol.
+    fn compress(&self, data: &[u8]) -> (Vec<u8>, usize) {
+        let mut out = Vec::with_capacity(data.len());
+        let mut symbol_count = 0;
+        let mut pos = 0;
+        while pos < data.len() {
+            match self.longest_match(&data[pos..]) {
+                Some((code, len)) => {
+                    out.push(code);
+                    pos += len;
+                }
+                None => {
+                    out.push(FSST_ESCAPE);
+                    out.push(data[pos]);
+                    pos += 1;
+                }
+            }
+            symbol_count += 1;
+        }
+        (out, symbol_count)
+    }
+
+    fn decompress(&self, data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(data.len() * 2);
+        let mut i = 0;
+        while i < data.len() {
+            let code = data[i];
+            if code == FSST_ESCAPE {
+                i += 1;
+                if i < data.len() {
+                    out.push(data[i]);
+                    i += 1;
+                }
+            } else {
+                if let Some(symbol) = self.symbols.get(code as usize) {
+                    out.extend_from_slice(symbol);
+                }
+                i += 1;
+            }
+        }
+        out
+    }
+}
+
+/// Compression codecs supported by `compress_with`. Kept as an explicit enum
+/// (rather than a free-form string) so unknown `algorithms` entries are
+/// caught once at parse time instead of scattering `match` fallbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompressionMethod {
+    NoCompression,
+    Gzip,
+    Zstd,
+    Brotli,
+    Lz4,
+    Bzip2,
+}
+
+impl CompressionMethod {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "none" | "store" => Ok(CompressionMethod::NoCompression),
+            "gzip" | "deflate" => Ok(CompressionMethod::Gzip),
+            "zstd" => Ok(CompressionMethod::Zstd),
+            "brotli" => Ok(CompressionMethod::Brotli),
+            "lz4" => Ok(CompressionMethod::Lz4),
+            "bzip2" => Ok(CompressionMethod::Bzip2),
+            other => Err(format!("Unknown compression algorithm: {}", other)),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            CompressionMethod::NoCompression => "none",
+            CompressionMethod::Gzip => "gzip",
+            CompressionMethod::Zstd => "zstd",
+            CompressionMethod::Brotli => "brotli",
+            CompressionMethod::Lz4 => "lz4",
+            CompressionMethod::Bzip2 => "bzip2",
+        }
+    }
+
+    /// The one-byte tag prepended to framed blobs, so `decompress` can
+    /// recover the codec without any side-channel metadata.
+    fn tag(&self) -> u8 {
+        match self {
+            CompressionMethod::NoCompression => 0,
+            CompressionMethod::Gzip => 1,
+            CompressionMethod::Zstd => 2,
+            CompressionMethod::Brotli => 3,
+            CompressionMethod::Lz4 => 4,
+            CompressionMethod::Bzip2 => 5,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Result<Self, String> {
+        match tag {
+            0 => Ok(CompressionMethod::NoCompression),
+            1 => Ok(CompressionMethod::Gzip),
+            2 => Ok(CompressionMethod::Zstd),
+            3 => Ok(CompressionMethod::Brotli),
+            4 => Ok(CompressionMethod::Lz4),
+            5 => Ok(CompressionMethod::Bzip2),
+            other => Err(format!("Unknown codec tag byte: {}", other)),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct CompressionResult {
     success: bool,
@@ -16,6 +301,19 @@ struct CompressionResult {
     compression_ratio: Option<f64>,
     compression_time: f64,
     throughput_mb_s: Option<f64>,
+    // Only set for FSST: original bytes represented per emitted code,
+    // i.e. how much a symbol "pays for itself" on average.
+    bytes_per_symbol: Option<f64>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct DecompressionResult {
+    success: bool,
+    decompressed_size: Option<usize>,
+    decompression_time: f64,
+    throughput_mb_s: Option<f64>,
+    round_trip_ok: bool,
     error: Option<String>,
 }
 
@@ -23,12 +321,14 @@ struct CompressionResult {
 struct IterationResult {
     iteration: u32,
     compression: CompressionResult,
+    decompression: DecompressionResult,
 }
 
 #[derive(Debug, Serialize)]
 struct TestCase {
     input_size: usize,
     data_type: String,
+    algorithm: String,
     compression_level: u32,
     iterations: Vec<IterationResult>,
     avg_compression_ratio: f64,
@@ -36,6 +336,8 @@ struct TestCase {
     avg_decompression_time: f64,
     avg_compression_throughput: f64,
     avg_decompression_throughput: f64,
+    fsst_train_time_ms: Option<f64>,
+    avg_bytes_per_symbol: Option<f64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -69,6 +371,7 @@ struct Parameters {
     input_sizes: Option<Vec<usize>>,
     data_types: Option<Vec<String>>,
     compression_levels: Option<Vec<u32>>,
+    algorithms: Option<Vec<String>>,
     iterations: Option<u32>,
 }
 
@@ -116,11 +419,28 @@ fn generate_test_data(size: usize, data_type: &str) -> Result<Vec<u8>, String> {
     }
 }
 
-fn compress_data(data: &[u8], compression_level: u32) -> CompressionResult {
+/// Splits `data` on whitespace into short, record-like strings for
+/// `fsst_bulk`'s "train one table, then compress many short strings
+/// independently" mode, since the generators above only produce a single
+/// large blob rather than a corpus of short records.
+fn split_into_short_strings(data: &[u8]) -> Vec<Vec<u8>> {
+    let strings: Vec<Vec<u8>> = data
+        .split(|&b| b == b' ' || b == b'\n')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_vec())
+        .collect();
+    if strings.is_empty() {
+        vec![data.to_vec()]
+    } else {
+        strings
+    }
+}
+
+fn compress_data(data: &[u8], method: CompressionMethod, compression_level: u32) -> (CompressionResult, Option<Vec<u8>>) {
     let start = Instant::now();
     let original_size = data.len();
-    
-    match try_compress_gzip(data, compression_level) {
+
+    match compress(method, data, compression_level) {
         Ok(compressed) => {
             let compression_time = start.elapsed().as_secs_f64() * 1000.0;
             let compressed_size = compressed.len();
@@ -130,32 +450,124 @@ fn compress_data(data: &[u8], compression_level: u32) -> CompressionResult {
                 0.0
             };
             let throughput = original_size as f64 / (compression_time / 1000.0) / (1024.0 * 1024.0);
-            
-            CompressionResult {
+
+            let result = CompressionResult {
                 success: true,
                 original_size: Some(original_size),
                 compressed_size: Some(compressed_size),
                 compression_ratio: Some((compression_ratio * 1000.0).round() / 1000.0),
                 compression_time: (compression_time * 100.0).round() / 100.0,
                 throughput_mb_s: Some((throughput * 100.0).round() / 100.0),
+                bytes_per_symbol: None,
                 error: None,
-            }
+            };
+            (result, Some(compressed))
         }
         Err(e) => {
             let compression_time = start.elapsed().as_secs_f64() * 1000.0;
-            CompressionResult {
+            let result = CompressionResult {
                 success: false,
                 original_size: Some(original_size),
                 compressed_size: None,
                 compression_ratio: None,
                 compression_time: (compression_time * 100.0).round() / 100.0,
                 throughput_mb_s: None,
+                bytes_per_symbol: None,
+                error: Some(e.to_string()),
+            };
+            (result, None)
+        }
+    }
+}
+
+/// Decompresses the self-describing `compressed` blob and verifies the
+/// result matches `original` byte-for-byte, so the benchmark never silently
+/// reports timings for a codec that doesn't actually round-trip.
+fn decompress_data(compressed: &[u8], original: &[u8]) -> DecompressionResult {
+    let start = Instant::now();
+
+    match decompress(compressed) {
+        Ok(decompressed) => {
+            let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+            let decompressed_size = decompressed.len();
+            let throughput = decompressed_size as f64 / (decompression_time / 1000.0) / (1024.0 * 1024.0);
+            let round_trip_ok = decompressed == original;
+
+            DecompressionResult {
+                success: true,
+                decompressed_size: Some(decompressed_size),
+                decompression_time: (decompression_time * 100.0).round() / 100.0,
+                throughput_mb_s: Some((throughput * 100.0).round() / 100.0),
+                round_trip_ok,
+                error: if round_trip_ok { None } else { Some("decompressed output does not match original data".to_string()) },
+            }
+        }
+        Err(e) => {
+            let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+            DecompressionResult {
+                success: false,
+                decompressed_size: None,
+                decompression_time: (decompression_time * 100.0).round() / 100.0,
+                throughput_mb_s: None,
+                round_trip_ok: false,
                 error: Some(e.to_string()),
             }
         }
     }
 }
 
+/// FSST counterpart to `compress_data`, using an already-trained `table`
+/// instead of the stateless `compress_with` dispatch.
+fn fsst_compress_data(data: &[u8], table: &FsstTable) -> (CompressionResult, Option<Vec<u8>>) {
+    let start = Instant::now();
+    let original_size = data.len();
+    let (compressed, symbol_count) = table.compress(data);
+    let compression_time = start.elapsed().as_secs_f64() * 1000.0;
+    let compressed_size = compressed.len();
+    let compression_ratio = if compressed_size > 0 {
+        original_size as f64 / compressed_size as f64
+    } else {
+        0.0
+    };
+    let throughput = original_size as f64 / (compression_time / 1000.0) / (1024.0 * 1024.0);
+    let bytes_per_symbol = if symbol_count > 0 {
+        original_size as f64 / symbol_count as f64
+    } else {
+        0.0
+    };
+
+    let result = CompressionResult {
+        success: true,
+        original_size: Some(original_size),
+        compressed_size: Some(compressed_size),
+        compression_ratio: Some((compression_ratio * 1000.0).round() / 1000.0),
+        compression_time: (compression_time * 100.0).round() / 100.0,
+        throughput_mb_s: Some((throughput * 100.0).round() / 100.0),
+        bytes_per_symbol: Some((bytes_per_symbol * 1000.0).round() / 1000.0),
+        error: None,
+    };
+    (result, Some(compressed))
+}
+
+/// FSST counterpart to `decompress_data`.
+fn fsst_decompress_data(compressed: &[u8], table: &FsstTable, original: &[u8]) -> DecompressionResult {
+    let start = Instant::now();
+    let decompressed = table.decompress(compressed);
+    let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+    let decompressed_size = decompressed.len();
+    let throughput = decompressed_size as f64 / (decompression_time / 1000.0) / (1024.0 * 1024.0);
+    let round_trip_ok = decompressed == original;
+
+    DecompressionResult {
+        success: true,
+        decompressed_size: Some(decompressed_size),
+        decompression_time: (decompression_time * 100.0).round() / 100.0,
+        throughput_mb_s: Some((throughput * 100.0).round() / 100.0),
+        round_trip_ok,
+        error: if round_trip_ok { None } else { Some("decompressed output does not match original data".to_string()) },
+    }
+}
+
 fn try_compress_gzip(data: &[u8], compression_level: u32) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
     let compression = match compression_level {
         1 => Compression::fast(),
@@ -164,16 +576,97 @@ fn try_compress_gzip(data: &[u8], compression_level: u32) -> Result<Vec<u8>, Box
         7..=9 => Compression::new(compression_level),
         _ => Compression::default(),
     };
-    
+
     let mut encoder = GzEncoder::new(Vec::new(), compression);
     encoder.write_all(data)?;
     Ok(encoder.finish()?)
 }
 
+/// Dispatches to the codec named by `method`. `compression_level` is clamped
+/// into zstd's `1..=22` and brotli's `0..=11` ranges, ignored by lz4, and
+/// mapped by bzip2 onto fast (1-3), that level (4-6) or best (anything else).
+fn compress_with(method: CompressionMethod, data: &[u8], compression_level: u32) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    match method {
+        CompressionMethod::NoCompression => Ok(data.to_vec()),
+        CompressionMethod::Gzip => try_compress_gzip(data, compression_level),
+        CompressionMethod::Zstd => {
+            let level = (compression_level as i32).clamp(1, 22);
+            Ok(zstd::encode_all(data, level)?)
+        }
+        CompressionMethod::Brotli => {
+            let quality = compression_level.clamp(0, 11);
+            let mut output = Vec::new();
+            let params = brotli::enc::BrotliEncoderParams {
+                quality: quality as i32,
+                ..Default::default()
+            };
+            brotli::BrotliCompress(&mut &data[..], &mut output, &params)?;
+            Ok(output)
+        }
+        CompressionMethod::Lz4 => Ok(lz4_flex::compress_prepend_size(data)),
+        CompressionMethod::Bzip2 => {
+            let level = match compression_level {
+                1..=3 => bzip2::Compression::fast(),
+                4..=6 => bzip2::Compression::new(compression_level),
+                _ => bzip2::Compression::best(),
+            };
+            let mut encoder = bzip2::write::BzEncoder::new(Vec::new(), level);
+            encoder.write_all(data)?;
+            Ok(encoder.finish()?)
+        }
+    }
+}
+
+/// The decode half of `compress_with`, one arm per codec.
+fn decompress_with(method: CompressionMethod, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    match method {
+        CompressionMethod::NoCompression => Ok(data.to_vec()),
+        CompressionMethod::Gzip => {
+            let mut decoder = flate2::read::GzDecoder::new(data);
+            let mut output = Vec::new();
+            std::io::Read::read_to_end(&mut decoder, &mut output)?;
+            Ok(output)
+        }
+        CompressionMethod::Zstd => Ok(zstd::decode_all(data)?),
+        CompressionMethod::Brotli => {
+            let mut output = Vec::new();
+            brotli::BrotliDecompress(&mut &data[..], &mut output)?;
+            Ok(output)
+        }
+        CompressionMethod::Lz4 => Ok(lz4_flex::decompress_size_prepended(data)?),
+        CompressionMethod::Bzip2 => {
+            let mut decoder = bzip2::read::BzDecoder::new(data);
+            let mut output = Vec::new();
+            std::io::Read::read_to_end(&mut decoder, &mut output)?;
+            Ok(output)
+        }
+    }
+}
+
+/// Compresses `data` with `method` and prepends a one-byte codec tag, so the
+/// resulting blob is self-describing and `decompress` needs no side-channel
+/// metadata about how it was produced.
+fn compress(method: CompressionMethod, data: &[u8], compression_level: u32) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let body = compress_with(method, data, compression_level)?;
+    let mut framed = Vec::with_capacity(body.len() + 1);
+    framed.push(method.tag());
+    framed.extend_from_slice(&body);
+    Ok(framed)
+}
+
+/// Reads the codec tag written by `compress` and dispatches to the matching
+/// decoder, making the round-trip path codec-agnostic.
+fn decompress(data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let (&tag, body) = data.split_first().ok_or("empty compressed blob: missing codec tag byte")?;
+    let method = CompressionMethod::from_tag(tag)?;
+    decompress_with(method, body)
+}
+
 fn run_compression_benchmark(config: Parameters) -> BenchmarkResults {
     let input_sizes = config.input_sizes.unwrap_or_else(|| vec![1024]);
     let data_types = config.data_types.unwrap_or_else(|| vec!["text".to_string()]);
     let compression_levels = config.compression_levels.unwrap_or_else(|| vec![6]);
+    let algorithms = config.algorithms.unwrap_or_else(|| vec!["gzip".to_string()]);
     let iterations = config.iterations.unwrap_or(5);
     
     let mut results = BenchmarkResults {
@@ -199,77 +692,183 @@ fn run_compression_benchmark(config: Parameters) -> BenchmarkResults {
     let mut total_compression_ratios = Vec::new();
     let mut total_compression_times = Vec::new();
     let mut total_compression_throughputs = Vec::new();
-    
+    let mut total_decompression_times = Vec::new();
+    let mut total_decompression_throughputs = Vec::new();
+
     for size in input_sizes {
         for data_type in &data_types {
-            for level in &compression_levels {
-                eprintln!("Testing {} data, size: {} bytes, level: {}...", data_type, size, level);
-                
-                let mut test_case = TestCase {
-                    input_size: size,
-                    data_type: data_type.clone(),
-                    compression_level: *level,
-                    iterations: Vec::new(),
-                    avg_compression_ratio: 0.0,
-                    avg_compression_time: 0.0,
-                    avg_decompression_time: 0.0,
-                    avg_compression_throughput: 0.0,
-                    avg_decompression_throughput: 0.0,
-                };
-                
-                let mut iteration_compression_ratios = Vec::new();
-                let mut iteration_compression_times = Vec::new();
-                let mut iteration_compression_throughputs = Vec::new();
-                
-                for i in 0..iterations {
-                    eprintln!("  Iteration {}/{}...", i + 1, iterations);
-                    
-                    let test_data = match generate_test_data(size, data_type) {
-                        Ok(data) => data,
+            for algorithm in &algorithms {
+                let is_fsst_bulk = algorithm.eq_ignore_ascii_case("fsst_bulk");
+                let is_fsst = algorithm.eq_ignore_ascii_case("fsst") || is_fsst_bulk;
+                if is_fsst && data_type != "text" && data_type != "json" {
+                    eprintln!("Skipping fsst for data type {}: FSST targets text/json", data_type);
+                    continue;
+                }
+                let method = if is_fsst {
+                    None
+                } else {
+                    match CompressionMethod::parse(algorithm) {
+                        Ok(method) => Some(method),
                         Err(e) => {
-                            eprintln!("Error generating test data: {}", e);
+                            eprintln!("Error: {}", e);
                             continue;
                         }
+                    }
+                };
+
+                for level in &compression_levels {
+                    eprintln!("Testing {} data, size: {} bytes, algorithm: {}, level: {}...", data_type, size, algorithm, level);
+
+                    let mut test_case = TestCase {
+                        input_size: size,
+                        data_type: data_type.clone(),
+                        algorithm: method.map(|m| m.as_str().to_string()).unwrap_or_else(|| algorithm.clone()),
+                        compression_level: *level,
+                        iterations: Vec::new(),
+                        avg_compression_ratio: 0.0,
+                        avg_compression_time: 0.0,
+                        avg_decompression_time: 0.0,
+                        avg_compression_throughput: 0.0,
+                        avg_decompression_throughput: 0.0,
+                        fsst_train_time_ms: None,
+                        avg_bytes_per_symbol: None,
                     };
-                    
-                    let compression_result = compress_data(&test_data, *level);
-                    
-                    let iteration_result = IterationResult {
-                        iteration: i + 1,
-                        compression: compression_result,
-                    };
-                    
-                    results.summary.total_tests += 1;
-                    
-                    if iteration_result.compression.success {
-                        results.summary.successful_tests += 1;
-                        
-                        if let Some(ratio) = iteration_result.compression.compression_ratio {
-                            iteration_compression_ratios.push(ratio);
-                        }
-                        iteration_compression_times.push(iteration_result.compression.compression_time);
-                        if let Some(throughput) = iteration_result.compression.throughput_mb_s {
-                            iteration_compression_throughputs.push(throughput);
+
+                    let mut iteration_compression_ratios = Vec::new();
+                    let mut iteration_compression_times = Vec::new();
+                    let mut iteration_compression_throughputs = Vec::new();
+                    let mut iteration_decompression_times = Vec::new();
+                    let mut iteration_decompression_throughputs = Vec::new();
+                    let mut iteration_bytes_per_symbol = Vec::new();
+
+                    // FSST amortizes training over the whole test case rather
+                    // than per iteration, so train once up front against a
+                    // representative sample and reuse the table below. In
+                    // `fsst_bulk` mode the "sample" is split into many short
+                    // strings up front too, so each iteration can compress
+                    // one independently with the shared table.
+                    let (fsst_table, bulk_strings) = if is_fsst {
+                        match generate_test_data(size, data_type) {
+                            Ok(sample) => {
+                                let train_start = Instant::now();
+                                let (table, strings) = if is_fsst_bulk {
+                                    let strings = split_into_short_strings(&sample);
+                                    let sample_refs: Vec<&[u8]> = strings.iter().map(|s| s.as_slice()).collect();
+                                    (FsstTable::train_bulk(&sample_refs), Some(strings))
+                                } else {
+                                    (FsstTable::train(&[&sample[..]]), None)
+                                };
+                                test_case.fsst_train_time_ms = Some((train_start.elapsed().as_secs_f64() * 1000.0 * 100.0).round() / 100.0);
+                                (Some(table), strings)
+                            }
+                            Err(e) => {
+                                eprintln!("Error generating FSST training sample: {}", e);
+                                (None, None)
+                            }
                         }
                     } else {
-                        results.summary.failed_tests += 1;
+                        (None, None)
+                    };
+
+                    for i in 0..iterations {
+                        eprintln!("  Iteration {}/{}...", i + 1, iterations);
+
+                        let test_data = match &bulk_strings {
+                            Some(strings) if !strings.is_empty() => strings[i as usize % strings.len()].clone(),
+                            Some(_) => {
+                                eprintln!("Error: fsst_bulk training produced no strings");
+                                continue;
+                            }
+                            None => match generate_test_data(size, data_type) {
+                                Ok(data) => data,
+                                Err(e) => {
+                                    eprintln!("Error generating test data: {}", e);
+                                    continue;
+                                }
+                            },
+                        };
+
+                        let (compression_result, compressed_bytes) = match (method, &fsst_table) {
+                            (Some(method), _) => compress_data(&test_data, method, *level),
+                            (None, Some(table)) => fsst_compress_data(&test_data, table),
+                            (None, None) => {
+                                eprintln!("Skipping iteration: no codec available");
+                                continue;
+                            }
+                        };
+
+                        let decompression_result = match (&compressed_bytes, &fsst_table) {
+                            (Some(compressed), Some(table)) if method.is_none() && compression_result.success => {
+                                fsst_decompress_data(compressed, table, &test_data)
+                            }
+                            (Some(compressed), _) if compression_result.success => decompress_data(compressed, &test_data),
+                            _ => DecompressionResult {
+                                success: false,
+                                decompressed_size: None,
+                                decompression_time: 0.0,
+                                throughput_mb_s: None,
+                                round_trip_ok: false,
+                                error: Some("compression failed; skipped decompression".to_string()),
+                            },
+                        };
+
+                        let iteration_result = IterationResult {
+                            iteration: i + 1,
+                            compression: compression_result,
+                            decompression: decompression_result,
+                        };
+
+                        results.summary.total_tests += 1;
+
+                        if iteration_result.compression.success && iteration_result.decompression.round_trip_ok {
+                            results.summary.successful_tests += 1;
+
+                            if let Some(ratio) = iteration_result.compression.compression_ratio {
+                                iteration_compression_ratios.push(ratio);
+                            }
+                            iteration_compression_times.push(iteration_result.compression.compression_time);
+                            if let Some(throughput) = iteration_result.compression.throughput_mb_s {
+                                iteration_compression_throughputs.push(throughput);
+                            }
+                            if let Some(bytes_per_symbol) = iteration_result.compression.bytes_per_symbol {
+                                iteration_bytes_per_symbol.push(bytes_per_symbol);
+                            }
+                            iteration_decompression_times.push(iteration_result.decompression.decompression_time);
+                            if let Some(throughput) = iteration_result.decompression.throughput_mb_s {
+                                iteration_decompression_throughputs.push(throughput);
+                            }
+                        } else {
+                            results.summary.failed_tests += 1;
+                        }
+
+                        test_case.iterations.push(iteration_result);
                     }
-                    
-                    test_case.iterations.push(iteration_result);
-                }
-                
-                // Calculate averages for this test case
-                if !iteration_compression_ratios.is_empty() {
-                    test_case.avg_compression_ratio = iteration_compression_ratios.iter().sum::<f64>() / iteration_compression_ratios.len() as f64;
-                    test_case.avg_compression_time = iteration_compression_times.iter().sum::<f64>() / iteration_compression_times.len() as f64;
-                    test_case.avg_compression_throughput = iteration_compression_throughputs.iter().sum::<f64>() / iteration_compression_throughputs.len() as f64;
-                    
-                    total_compression_ratios.extend(iteration_compression_ratios);
-                    total_compression_times.extend(iteration_compression_times);
-                    total_compression_throughputs.extend(iteration_compression_throughputs);
+
+                    // Calculate averages for this test case
+                    if !iteration_compression_ratios.is_empty() {
+                        test_case.avg_compression_ratio = iteration_compression_ratios.iter().sum::<f64>() / iteration_compression_ratios.len() as f64;
+                        test_case.avg_compression_time = iteration_compression_times.iter().sum::<f64>() / iteration_compression_times.len() as f64;
+                        test_case.avg_compression_throughput = iteration_compression_throughputs.iter().sum::<f64>() / iteration_compression_throughputs.len() as f64;
+
+                        total_compression_ratios.extend(iteration_compression_ratios);
+                        total_compression_times.extend(iteration_compression_times);
+                        total_compression_throughputs.extend(iteration_compression_throughputs);
+                    }
+                    if !iteration_bytes_per_symbol.is_empty() {
+                        test_case.avg_bytes_per_symbol = Some(
+                            iteration_bytes_per_symbol.iter().sum::<f64>() / iteration_bytes_per_symbol.len() as f64,
+                        );
+                    }
+                    if !iteration_decompression_times.is_empty() {
+                        test_case.avg_decompression_time = iteration_decompression_times.iter().sum::<f64>() / iteration_decompression_times.len() as f64;
+                        test_case.avg_decompression_throughput = iteration_decompression_throughputs.iter().sum::<f64>() / iteration_decompression_throughputs.len() as f64;
+
+                        total_decompression_times.extend(iteration_decompression_times);
+                        total_decompression_throughputs.extend(iteration_decompression_throughputs);
+                    }
+
+                    results.test_cases.push(test_case);
                 }
-                
-                results.test_cases.push(test_case);
             }
         }
     }
@@ -280,7 +879,11 @@ fn run_compression_benchmark(config: Parameters) -> BenchmarkResults {
         results.summary.avg_compression_time = total_compression_times.iter().sum::<f64>() / total_compression_times.len() as f64;
         results.summary.avg_compression_throughput = total_compression_throughputs.iter().sum::<f64>() / total_compression_throughputs.len() as f64;
     }
-    
+    if !total_decompression_times.is_empty() {
+        results.summary.avg_decompression_time = total_decompression_times.iter().sum::<f64>() / total_decompression_times.len() as f64;
+        results.summary.avg_decompression_throughput = total_decompression_throughputs.iter().sum::<f64>() / total_decompression_throughputs.len() as f64;
+    }
+
     let end_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
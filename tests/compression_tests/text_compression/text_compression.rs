@@ -8,8 +8,10 @@ use rand::Rng;
 use rand::seq::SliceRandom;
 use flate2::write::GzEncoder;
 use flate2::write::ZlibEncoder;
+use flate2::read::GzDecoder;
+use flate2::read::ZlibDecoder;
 use flate2::Compression;
-use std::io::Write;
+use std::io::{Read, Write};
 
 #[derive(Debug, Serialize)]
 struct CompressionResult {
@@ -33,6 +35,11 @@ struct IterationResult {
     original_size: usize,
     compression: CompressionResult,
     decompression: Option<DecompressionResult>,
+    /// Whether decompressing `compression`'s output reproduced the
+    /// original bytes exactly. `false` whenever compression or
+    /// decompression itself failed, so a broken codec can't hide behind a
+    /// "decompression: None" entry.
+    round_trip_ok: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -40,10 +47,13 @@ struct TestCase {
     input_size: usize,
     text_type: String,
     algorithm: String,
+    level: u8,
     iterations: Vec<IterationResult>,
     avg_compression_ratio: f64,
     avg_compression_time: f64,
     avg_decompression_time: f64,
+    avg_compression_throughput_mb_s: f64,
+    avg_decompression_throughput_mb_s: f64,
 }
 
 #[derive(Debug, Serialize)]
@@ -51,6 +61,7 @@ struct AlgorithmPerformance {
     avg_compression_ratio: f64,
     max_compression_ratio: f64,
     min_compression_ratio: f64,
+    avg_compression_throughput_mb_s: f64,
 }
 
 #[derive(Debug, Serialize)]
@@ -82,6 +93,18 @@ struct Parameters {
     text_types: Option<Vec<String>>,
     compression_algorithms: Option<Vec<String>>,
     iterations: Option<u32>,
+    /// Explicit levels to sweep for every algorithm, clamped into each
+    /// codec's own valid range. When absent, each codec's
+    /// `default_levels()` is used instead, since "0..=9" isn't a
+    /// meaningful range for e.g. zstd or lz4.
+    compression_levels: Option<Vec<u8>>,
+    /// Real files to benchmark against (a corpus, a source tree, a CSV,
+    /// ...) alongside or instead of `text_types`' synthetic data, since
+    /// synthetic ascii/unicode/code/natural_language strings have very
+    /// different entropy characteristics than real-world input. Each file
+    /// is chunked by `input_sizes` and reported under a `text_type` equal
+    /// to the file's basename.
+    input_files: Option<Vec<String>>,
 }
 
 fn generate_text_data(size: usize, text_type: &str) -> Result<String, String> {
@@ -157,77 +180,431 @@ fn generate_text_data(size: usize, text_type: &str) -> Result<String, String> {
     }
 }
 
-fn compress_with_gzip(data: &[u8]) -> CompressionResult {
-    let start = Instant::now();
-    
-    match try_compress_gzip(data) {
-        Ok(compressed) => {
-            let compression_time = start.elapsed().as_secs_f64() * 1000.0;
-            CompressionResult {
-                success: true,
-                compressed_size: Some(compressed.len()),
-                compression_time,
-                error: None,
-            }
+/// One `chunk_size`-byte slice of a real input file, labelled with the
+/// file's basename.
+struct FileChunk {
+    label: String,
+    bytes: Vec<u8>,
+}
+
+/// Reads `path` and splits it into non-overlapping `chunk_size`-byte
+/// chunks. Files shorter than `chunk_size` yield a single, shorter chunk,
+/// since real corpora aren't always large enough to fill every requested
+/// size.
+fn load_file_chunks(path: &str, chunk_size: usize) -> Result<Vec<FileChunk>, Box<dyn std::error::Error>> {
+    let data = fs::read(path)?;
+    let label = std::path::Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string());
+
+    if data.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let chunks = if data.len() <= chunk_size {
+        vec![FileChunk { label, bytes: data }]
+    } else {
+        data.chunks(chunk_size)
+            .map(|c| FileChunk { label: label.clone(), bytes: c.to_vec() })
+            .collect()
+    };
+
+    Ok(chunks)
+}
+
+/// A labelled source of test data: either synthetic (generated fresh per
+/// iteration, the existing behavior) or a real file (chunked once per
+/// size, then cycled through across iterations).
+enum DataSource {
+    Synthetic(String),
+    File(String),
+}
+
+/// A compression algorithm that can be selected by name from the
+/// `compression_algorithms` config list. `compress`/`decompress` return
+/// the raw bytes alongside the timing/outcome metadata (rather than just
+/// `CompressionResult`/`DecompressionResult`) so the caller can round-trip
+/// the output without the codec needing to know about `IterationResult`.
+trait Codec {
+    fn name(&self) -> &str;
+    fn compress(&self, data: &[u8], level: u8) -> (CompressionResult, Option<Vec<u8>>);
+    fn decompress(&self, data: &[u8]) -> (DecompressionResult, Option<Vec<u8>>);
+    /// The representative low/mid/high levels to sweep when the config
+    /// doesn't name explicit ones, since each codec's scale is different
+    /// (deflate's 0..=9 isn't zstd's 1..=22 or lz4's none at all).
+    fn default_levels(&self) -> Vec<u8>;
+    /// Clamps a config-supplied level into this codec's valid range.
+    fn clamp_level(&self, level: u8) -> u8;
+}
+
+/// The levels to sweep for one codec: the config's explicit list if given
+/// (clamped into the codec's range and deduplicated), otherwise the
+/// codec's own representative defaults.
+fn levels_for(codec: &dyn Codec, configured: &Option<Vec<u8>>) -> Vec<u8> {
+    match configured {
+        Some(levels) if !levels.is_empty() => {
+            let mut clamped: Vec<u8> = levels.iter().map(|&l| codec.clamp_level(l)).collect();
+            clamped.sort_unstable();
+            clamped.dedup();
+            clamped
         }
-        Err(e) => {
-            let compression_time = start.elapsed().as_secs_f64() * 1000.0;
-            CompressionResult {
-                success: false,
-                compressed_size: None,
-                compression_time,
-                error: Some(e.to_string()),
-            }
+        _ => codec.default_levels(),
+    }
+}
+
+/// Converts a byte count and an elapsed time in milliseconds into MB/sec,
+/// so results normalize by work done rather than wall-clock alone.
+fn throughput_mb_s(bytes: usize, time_ms: f64) -> f64 {
+    if time_ms > 0.0 {
+        (bytes as f64 / (1024.0 * 1024.0)) / (time_ms / 1000.0)
+    } else {
+        0.0
+    }
+}
+
+fn codec_for(name: &str) -> Option<Box<dyn Codec>> {
+    match name {
+        "gzip" => Some(Box::new(GzipCodec)),
+        "zlib" => Some(Box::new(ZlibCodec)),
+        "brotli" => Some(Box::new(BrotliCodec)),
+        "lzma" | "xz" => Some(Box::new(LzmaCodec)),
+        "lz4" => Some(Box::new(Lz4Codec)),
+        "zstd" => Some(Box::new(ZstdCodec)),
+        _ => None,
+    }
+}
+
+struct GzipCodec;
+
+impl Codec for GzipCodec {
+    fn name(&self) -> &str {
+        "gzip"
+    }
+
+    fn compress(&self, data: &[u8], level: u8) -> (CompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let result = (|| -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level.min(9) as u32));
+            encoder.write_all(data)?;
+            Ok(encoder.finish()?)
+        })();
+        let compression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(compressed) => (
+                CompressionResult { success: true, compressed_size: Some(compressed.len()), compression_time, error: None },
+                Some(compressed),
+            ),
+            Err(e) => (
+                CompressionResult { success: false, compressed_size: None, compression_time, error: Some(e.to_string()) },
+                None,
+            ),
         }
     }
+
+    fn decompress(&self, data: &[u8]) -> (DecompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let mut out = Vec::new();
+        let result = GzDecoder::new(data).read_to_end(&mut out);
+        let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(_) => (
+                DecompressionResult { success: true, decompressed_size: Some(out.len()), decompression_time, error: None },
+                Some(out),
+            ),
+            Err(e) => (
+                DecompressionResult { success: false, decompressed_size: None, decompression_time, error: Some(e.to_string()) },
+                None,
+            ),
+        }
+    }
+
+    fn default_levels(&self) -> Vec<u8> {
+        vec![1, 6, 9]
+    }
+
+    fn clamp_level(&self, level: u8) -> u8 {
+        level.min(9)
+    }
 }
 
-fn try_compress_gzip(data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
-    encoder.write_all(data)?;
-    Ok(encoder.finish()?)
+struct ZlibCodec;
+
+impl Codec for ZlibCodec {
+    fn name(&self) -> &str {
+        "zlib"
+    }
+
+    fn compress(&self, data: &[u8], level: u8) -> (CompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let result = (|| -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::new(level.min(9) as u32));
+            encoder.write_all(data)?;
+            Ok(encoder.finish()?)
+        })();
+        let compression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(compressed) => (
+                CompressionResult { success: true, compressed_size: Some(compressed.len()), compression_time, error: None },
+                Some(compressed),
+            ),
+            Err(e) => (
+                CompressionResult { success: false, compressed_size: None, compression_time, error: Some(e.to_string()) },
+                None,
+            ),
+        }
+    }
+
+    fn decompress(&self, data: &[u8]) -> (DecompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let mut out = Vec::new();
+        let result = ZlibDecoder::new(data).read_to_end(&mut out);
+        let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(_) => (
+                DecompressionResult { success: true, decompressed_size: Some(out.len()), decompression_time, error: None },
+                Some(out),
+            ),
+            Err(e) => (
+                DecompressionResult { success: false, decompressed_size: None, decompression_time, error: Some(e.to_string()) },
+                None,
+            ),
+        }
+    }
+
+    fn default_levels(&self) -> Vec<u8> {
+        vec![1, 6, 9]
+    }
+
+    fn clamp_level(&self, level: u8) -> u8 {
+        level.min(9)
+    }
 }
 
-fn compress_with_zlib(data: &[u8]) -> CompressionResult {
-    let start = Instant::now();
-    
-    match try_compress_zlib(data) {
-        Ok(compressed) => {
-            let compression_time = start.elapsed().as_secs_f64() * 1000.0;
-            CompressionResult {
-                success: true,
-                compressed_size: Some(compressed.len()),
-                compression_time,
-                error: None,
-            }
+struct BrotliCodec;
+
+impl Codec for BrotliCodec {
+    fn name(&self) -> &str {
+        "brotli"
+    }
+
+    fn compress(&self, data: &[u8], level: u8) -> (CompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let quality = level.min(11) as u32;
+        let mut out = Vec::new();
+        let result = {
+            let mut writer = brotli::CompressorWriter::new(&mut out, 4096, quality, 22);
+            writer.write_all(data)
+        };
+        let compression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(_) => (
+                CompressionResult { success: true, compressed_size: Some(out.len()), compression_time, error: None },
+                Some(out),
+            ),
+            Err(e) => (
+                CompressionResult { success: false, compressed_size: None, compression_time, error: Some(e.to_string()) },
+                None,
+            ),
         }
-        Err(e) => {
-            let compression_time = start.elapsed().as_secs_f64() * 1000.0;
-            CompressionResult {
-                success: false,
-                compressed_size: None,
-                compression_time,
-                error: Some(e.to_string()),
-            }
+    }
+
+    fn decompress(&self, data: &[u8]) -> (DecompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let mut out = Vec::new();
+        let result = brotli::Decompressor::new(data, 4096).read_to_end(&mut out);
+        let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(_) => (
+                DecompressionResult { success: true, decompressed_size: Some(out.len()), decompression_time, error: None },
+                Some(out),
+            ),
+            Err(e) => (
+                DecompressionResult { success: false, decompressed_size: None, decompression_time, error: Some(e.to_string()) },
+                None,
+            ),
+        }
+    }
+
+    fn default_levels(&self) -> Vec<u8> {
+        vec![1, 6, 11]
+    }
+
+    fn clamp_level(&self, level: u8) -> u8 {
+        level.min(11)
+    }
+}
+
+struct LzmaCodec;
+
+impl Codec for LzmaCodec {
+    fn name(&self) -> &str {
+        "lzma"
+    }
+
+    fn compress(&self, data: &[u8], level: u8) -> (CompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let result = (|| -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+            let mut encoder = xz2::write::XzEncoder::new(Vec::new(), level.min(9) as u32);
+            encoder.write_all(data)?;
+            Ok(encoder.finish()?)
+        })();
+        let compression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(compressed) => (
+                CompressionResult { success: true, compressed_size: Some(compressed.len()), compression_time, error: None },
+                Some(compressed),
+            ),
+            Err(e) => (
+                CompressionResult { success: false, compressed_size: None, compression_time, error: Some(e.to_string()) },
+                None,
+            ),
+        }
+    }
+
+    fn decompress(&self, data: &[u8]) -> (DecompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let mut out = Vec::new();
+        let result = xz2::read::XzDecoder::new(data).read_to_end(&mut out);
+        let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(_) => (
+                DecompressionResult { success: true, decompressed_size: Some(out.len()), decompression_time, error: None },
+                Some(out),
+            ),
+            Err(e) => (
+                DecompressionResult { success: false, decompressed_size: None, decompression_time, error: Some(e.to_string()) },
+                None,
+            ),
+        }
+    }
+
+    fn default_levels(&self) -> Vec<u8> {
+        vec![1, 6, 9]
+    }
+
+    fn clamp_level(&self, level: u8) -> u8 {
+        level.min(9)
+    }
+}
+
+struct Lz4Codec;
+
+impl Codec for Lz4Codec {
+    fn name(&self) -> &str {
+        "lz4"
+    }
+
+    /// `lz4_flex`'s safe block API has no tunable ratio-vs-speed level like
+    /// the deflate family does; `level` is accepted for interface symmetry
+    /// with the other codecs but has no effect here.
+    fn compress(&self, data: &[u8], _level: u8) -> (CompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let compressed = lz4_flex::compress_prepend_size(data);
+        let compression_time = start.elapsed().as_secs_f64() * 1000.0;
+        (
+            CompressionResult { success: true, compressed_size: Some(compressed.len()), compression_time, error: None },
+            Some(compressed),
+        )
+    }
+
+    fn decompress(&self, data: &[u8]) -> (DecompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let result = lz4_flex::decompress_size_prepended(data);
+        let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(decompressed) => (
+                DecompressionResult { success: true, decompressed_size: Some(decompressed.len()), decompression_time, error: None },
+                Some(decompressed),
+            ),
+            Err(e) => (
+                DecompressionResult { success: false, decompressed_size: None, decompression_time, error: Some(e.to_string()) },
+                None,
+            ),
         }
     }
+
+    /// Not tunable, so there's exactly one "level" to sweep.
+    fn default_levels(&self) -> Vec<u8> {
+        vec![0]
+    }
+
+    fn clamp_level(&self, _level: u8) -> u8 {
+        0
+    }
 }
 
-fn try_compress_zlib(data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
-    encoder.write_all(data)?;
-    Ok(encoder.finish()?)
+struct ZstdCodec;
+
+impl Codec for ZstdCodec {
+    fn name(&self) -> &str {
+        "zstd"
+    }
+
+    fn compress(&self, data: &[u8], level: u8) -> (CompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let result = zstd::stream::encode_all(data, (level as i32).clamp(1, 22));
+        let compression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(compressed) => (
+                CompressionResult { success: true, compressed_size: Some(compressed.len()), compression_time, error: None },
+                Some(compressed),
+            ),
+            Err(e) => (
+                CompressionResult { success: false, compressed_size: None, compression_time, error: Some(e.to_string()) },
+                None,
+            ),
+        }
+    }
+
+    fn decompress(&self, data: &[u8]) -> (DecompressionResult, Option<Vec<u8>>) {
+        let start = Instant::now();
+        let result = zstd::stream::decode_all(data);
+        let decompression_time = start.elapsed().as_secs_f64() * 1000.0;
+        match result {
+            Ok(decompressed) => (
+                DecompressionResult { success: true, decompressed_size: Some(decompressed.len()), decompression_time, error: None },
+                Some(decompressed),
+            ),
+            Err(e) => (
+                DecompressionResult { success: false, decompressed_size: None, decompression_time, error: Some(e.to_string()) },
+                None,
+            ),
+        }
+    }
+
+    fn default_levels(&self) -> Vec<u8> {
+        vec![1, 9, 19]
+    }
+
+    fn clamp_level(&self, level: u8) -> u8 {
+        (level as i32).clamp(1, 22) as u8
+    }
 }
 
+
 fn run_text_compression_benchmark(config: &Parameters) -> Result<BenchmarkResults, Box<dyn std::error::Error>> {
     let default_sizes = vec![1024];
     let default_types = vec!["ascii".to_string()];
     let default_algorithms = vec!["gzip".to_string()];
-    
+
     let input_sizes = config.input_sizes.as_ref().unwrap_or(&default_sizes);
-    let text_types = config.text_types.as_ref().unwrap_or(&default_types);
     let algorithms = config.compression_algorithms.as_ref().unwrap_or(&default_algorithms);
     let iterations = config.iterations.unwrap_or(3);
+
+    // Synthetic types fall back to the "ascii" default only when there are
+    // no real files to test either; otherwise an `input_files`-only config
+    // shouldn't silently also benchmark synthetic data nobody asked for.
+    let text_types: &[String] = match &config.text_types {
+        Some(types) => types,
+        None if config.input_files.is_none() => &default_types,
+        None => &[],
+    };
+
+    let mut sources: Vec<DataSource> = text_types.iter().cloned().map(DataSource::Synthetic).collect();
+    if let Some(files) = &config.input_files {
+        sources.extend(files.iter().cloned().map(DataSource::File));
+    }
     
     let mut results = BenchmarkResults {
         start_time: std::time::SystemTime::now()
@@ -246,78 +623,141 @@ fn run_text_compression_benchmark(config: &Parameters) -> Result<BenchmarkResult
     };
     
     let mut algorithm_stats: HashMap<String, Vec<f64>> = HashMap::new();
-    
+    let mut algorithm_throughput_stats: HashMap<String, Vec<f64>> = HashMap::new();
+
     for &size in input_sizes {
-        for text_type in text_types {
+        for source in &sources {
+            let (label, file_chunks) = match source {
+                DataSource::Synthetic(text_type) => (text_type.clone(), None),
+                DataSource::File(path) => {
+                    let chunks = match load_file_chunks(path, size) {
+                        Ok(chunks) => chunks,
+                        Err(_) => Vec::new(),
+                    };
+                    if chunks.is_empty() {
+                        eprintln!("Warning: input file {} is empty or unreadable, skipping", path);
+                        continue;
+                    }
+                    let label = chunks[0].label.clone();
+                    (label, Some(chunks))
+                }
+            };
+
             for algorithm in algorithms {
-                eprintln!("Testing {} text, size: {}, algorithm: {}...", text_type, size, algorithm);
-                
-                let mut test_case = TestCase {
-                    input_size: size,
-                    text_type: text_type.clone(),
-                    algorithm: algorithm.clone(),
-                    iterations: Vec::new(),
-                    avg_compression_ratio: 0.0,
-                    avg_compression_time: 0.0,
-                    avg_decompression_time: 0.0,
+                eprintln!("Testing {} text, size: {}, algorithm: {}...", label, size, algorithm);
+
+                let codec = match codec_for(algorithm) {
+                    Some(c) => c,
+                    None => {
+                        eprintln!("Warning: Algorithm {} not implemented, skipping", algorithm);
+                        continue;
+                    }
                 };
-                
-                let mut compression_ratios = Vec::new();
-                let mut compression_times = Vec::new();
-                
-                for i in 0..iterations {
-                    eprintln!("  Iteration {}/{}...", i + 1, iterations);
-                    
-                    let text_data = generate_text_data(size, text_type)?;
-                    let data_bytes = text_data.as_bytes();
-                    let original_size = data_bytes.len();
-                    
-                    let compress_result = match algorithm.as_str() {
-                        "gzip" => compress_with_gzip(data_bytes),
-                        "zlib" => compress_with_zlib(data_bytes),
-                        _ => {
-                            eprintln!("Warning: Algorithm {} not implemented, skipping", algorithm);
-                            continue;
-                        }
-                    };
-                    
-                    let iteration_result = IterationResult {
-                        iteration: i + 1,
-                        original_size,
-                        compression: compress_result,
-                        decompression: None,
+
+                for level in levels_for(codec.as_ref(), &config.compression_levels) {
+                    eprintln!("  Level {}...", level);
+
+                    let mut test_case = TestCase {
+                        input_size: size,
+                        text_type: label.clone(),
+                        algorithm: algorithm.clone(),
+                        level,
+                        iterations: Vec::new(),
+                        avg_compression_ratio: 0.0,
+                        avg_compression_time: 0.0,
+                        avg_decompression_time: 0.0,
+                        avg_compression_throughput_mb_s: 0.0,
+                        avg_decompression_throughput_mb_s: 0.0,
                     };
-                    
-                    results.summary.total_tests += 1;
-                    
-                    if iteration_result.compression.success {
-                        results.summary.successful_compressions += 1;
-                        
-                        if let Some(compressed_size) = iteration_result.compression.compressed_size {
-                            let compression_ratio = if compressed_size > 0 {
-                                original_size as f64 / compressed_size as f64
-                            } else {
-                                0.0
-                            };
-                            
-                            compression_ratios.push(compression_ratio);
-                            compression_times.push(iteration_result.compression.compression_time);
-                            
-                            algorithm_stats.entry(algorithm.clone()).or_insert_with(Vec::new).push(compression_ratio);
+
+                    let mut compression_ratios = Vec::new();
+                    let mut compression_times = Vec::new();
+                    let mut decompression_times = Vec::new();
+                    let mut compression_throughputs = Vec::new();
+                    let mut decompression_throughputs = Vec::new();
+
+                    for i in 0..iterations {
+                        eprintln!("    Iteration {}/{}...", i + 1, iterations);
+
+                        let owned_data;
+                        let data_bytes: &[u8] = match &file_chunks {
+                            Some(chunks) => &chunks[i as usize % chunks.len()].bytes,
+                            None => {
+                                owned_data = generate_text_data(size, &label)?;
+                                owned_data.as_bytes()
+                            }
+                        };
+                        let original_size = data_bytes.len();
+
+                        let (compress_result, compressed_bytes) = codec.compress(data_bytes, level);
+
+                        let (decompress_result, decompressed_bytes) = match (compress_result.success, &compressed_bytes) {
+                            (true, Some(compressed)) => codec.decompress(compressed),
+                            _ => (
+                                DecompressionResult {
+                                    success: false,
+                                    decompressed_size: None,
+                                    decompression_time: 0.0,
+                                    error: Some("Skipped: compression did not succeed".to_string()),
+                                },
+                                None,
+                            ),
+                        };
+
+                        let round_trip_ok = decompressed_bytes.as_deref() == Some(data_bytes);
+
+                        let iteration_result = IterationResult {
+                            iteration: i + 1,
+                            original_size,
+                            compression: compress_result,
+                            decompression: Some(decompress_result),
+                            round_trip_ok,
+                        };
+
+                        results.summary.total_tests += 1;
+
+                        if iteration_result.compression.success && round_trip_ok {
+                            results.summary.successful_compressions += 1;
+
+                            if let Some(compressed_size) = iteration_result.compression.compressed_size {
+                                let compression_ratio = if compressed_size > 0 {
+                                    original_size as f64 / compressed_size as f64
+                                } else {
+                                    0.0
+                                };
+
+                                compression_ratios.push(compression_ratio);
+                                compression_times.push(iteration_result.compression.compression_time);
+                                let compression_throughput = throughput_mb_s(original_size, iteration_result.compression.compression_time);
+                                compression_throughputs.push(compression_throughput);
+                                if let Some(decompression) = &iteration_result.decompression {
+                                    decompression_times.push(decompression.decompression_time);
+                                    decompression_throughputs.push(throughput_mb_s(original_size, decompression.decompression_time));
+                                }
+
+                                let stats_key = format!("{}-{}", algorithm, level);
+                                algorithm_stats.entry(stats_key.clone()).or_insert_with(Vec::new).push(compression_ratio);
+                                algorithm_throughput_stats.entry(stats_key).or_insert_with(Vec::new).push(compression_throughput);
+                            }
+                        } else {
+                            results.summary.failed_compressions += 1;
                         }
-                    } else {
-                        results.summary.failed_compressions += 1;
+
+                        test_case.iterations.push(iteration_result);
                     }
-                    
-                    test_case.iterations.push(iteration_result);
-                }
-                
-                if !compression_ratios.is_empty() {
-                    test_case.avg_compression_ratio = compression_ratios.iter().sum::<f64>() / compression_ratios.len() as f64;
-                    test_case.avg_compression_time = compression_times.iter().sum::<f64>() / compression_times.len() as f64;
+
+                    if !compression_ratios.is_empty() {
+                        test_case.avg_compression_ratio = compression_ratios.iter().sum::<f64>() / compression_ratios.len() as f64;
+                        test_case.avg_compression_time = compression_times.iter().sum::<f64>() / compression_times.len() as f64;
+                        test_case.avg_compression_throughput_mb_s = compression_throughputs.iter().sum::<f64>() / compression_throughputs.len() as f64;
+                    }
+                    if !decompression_times.is_empty() {
+                        test_case.avg_decompression_time = decompression_times.iter().sum::<f64>() / decompression_times.len() as f64;
+                        test_case.avg_decompression_throughput_mb_s = decompression_throughputs.iter().sum::<f64>() / decompression_throughputs.len() as f64;
+                    }
+
+                    results.test_cases.push(test_case);
                 }
-                
-                results.test_cases.push(test_case);
             }
         }
     }
@@ -328,11 +768,17 @@ fn run_text_compression_benchmark(config: &Parameters) -> Result<BenchmarkResult
             let avg = ratios.iter().sum::<f64>() / ratios.len() as f64;
             let max = ratios.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
             let min = ratios.iter().fold(f64::INFINITY, |a, &b| a.min(b));
-            
+            let avg_throughput = algorithm_throughput_stats
+                .get(&algorithm)
+                .filter(|throughputs| !throughputs.is_empty())
+                .map(|throughputs| throughputs.iter().sum::<f64>() / throughputs.len() as f64)
+                .unwrap_or(0.0);
+
             results.summary.algorithm_performance.insert(algorithm, AlgorithmPerformance {
                 avg_compression_ratio: avg,
                 max_compression_ratio: max,
                 min_compression_ratio: min,
+                avg_compression_throughput_mb_s: avg_throughput,
             });
         }
     }
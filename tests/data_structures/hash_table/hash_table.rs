@@ -53,7 +53,15 @@ fn main() {
     let delete_time = delete_start.elapsed();
     
     let total_time = total_start.elapsed();
-    
+
+    let ops_per_sec = |count: usize, elapsed: std::time::Duration| -> f64 {
+        if elapsed.as_secs_f64() > 0.0 {
+            count as f64 / elapsed.as_secs_f64()
+        } else {
+            0.0
+        }
+    };
+
     println!("Result:");
     println!("  Inserted: {} items", num_operations);
     println!("  Found: {}/{} items", found_count, num_operations);
@@ -64,4 +72,8 @@ fn main() {
     println!("  Lookup time: {:.6} seconds", lookup_time.as_secs_f64());
     println!("  Delete time: {:.6} seconds", delete_time.as_secs_f64());
     println!("  Total time: {:.6} seconds", total_time.as_secs_f64());
+    println!("Throughput:");
+    println!("  Inserts/sec: {:.2}", ops_per_sec(num_operations, insert_time));
+    println!("  Lookups/sec: {:.2}", ops_per_sec(num_operations, lookup_time));
+    println!("  Deletes/sec: {:.2}", ops_per_sec(deleted_count, delete_time));
 }
@@ -5,6 +5,103 @@ use std::collections::HashMap;
 use serde_json::{json, Value};
 use rand::Rng;
 
+/// A log-spaced latency histogram: `NUM_BUCKETS` edges spanning `MIN_SECONDS`
+/// to `MAX_SECONDS` on a log scale, so a handful of buckets covers latencies
+/// from microseconds to seconds without needing to know the scale in
+/// advance. Recording a duration increments the first bucket whose upper
+/// edge is `>=` it; percentiles walk the cumulative counts until the target
+/// fraction is reached. The raw bucket counts are kept in the output so
+/// histograms recorded by other languages' implementations can be merged.
+struct LatencyHistogram {
+    edges: Vec<f64>,
+    counts: Vec<u64>,
+}
+
+impl LatencyHistogram {
+    const NUM_BUCKETS: usize = 128;
+    const MIN_SECONDS: f64 = 1e-6;
+    const MAX_SECONDS: f64 = 10.0;
+
+    fn new() -> Self {
+        let n = Self::NUM_BUCKETS;
+        let ratio = Self::MAX_SECONDS / Self::MIN_SECONDS;
+        let edges = (0..n)
+            .map(|i| Self::MIN_SECONDS * ratio.powf(i as f64 / (n - 1) as f64))
+            .collect();
+        LatencyHistogram { edges, counts: vec![0; n] }
+    }
+
+    fn record(&mut self, seconds: f64) {
+        let bucket = match self.edges.binary_search_by(|edge| edge.partial_cmp(&seconds).unwrap()) {
+            Ok(idx) => idx,
+            Err(idx) => idx,
+        };
+        let bucket = bucket.min(self.counts.len() - 1);
+        self.counts[bucket] += 1;
+    }
+
+    /// Walks cumulative bucket counts until the cumulative fraction reaches
+    /// `p` (e.g. `0.99` for p99), returning that bucket's upper edge in
+    /// milliseconds.
+    fn percentile_ms(&self, p: f64) -> f64 {
+        let total: u64 = self.counts.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let target = (p * total as f64).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (i, &count) in self.counts.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                return self.edges[i] * 1000.0;
+            }
+        }
+        self.edges[self.edges.len() - 1] * 1000.0
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "p50": self.percentile_ms(0.50),
+            "p90": self.percentile_ms(0.90),
+            "p99": self.percentile_ms(0.99),
+            "p999": self.percentile_ms(0.999),
+            "bucket_edges_ms": self.edges.iter().map(|e| e * 1000.0).collect::<Vec<_>>(),
+            "bucket_counts": self.counts,
+        })
+    }
+}
+
+/// Sorts a copy of `times` and discards the bottom and top `trim_pct`
+/// fraction of samples from each end (e.g. `0.1` for a 10% trim), returning
+/// the retained, still-sorted samples. `trim_pct` is clamped to `0.0..=0.49`
+/// so at least one sample always survives; `0.0` (the default) keeps every
+/// sample.
+fn trim_samples(times: &[f64], trim_pct: f64) -> Vec<f64> {
+    if times.is_empty() {
+        return Vec::new();
+    }
+    let mut sorted = times.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let trim_count = (sorted.len() as f64 * trim_pct.clamp(0.0, 0.49)).floor() as usize;
+    let end = sorted.len() - trim_count;
+    if trim_count < end {
+        sorted[trim_count..end].to_vec()
+    } else {
+        sorted
+    }
+}
+
+/// Mean over `times` after discarding the top/bottom `trim_pct` fraction via
+/// `trim_samples`. `0.0` for `trim_pct` is a plain mean.
+fn trimmed_mean(times: &[f64], trim_pct: f64) -> f64 {
+    let retained = trim_samples(times, trim_pct);
+    if retained.is_empty() {
+        0.0
+    } else {
+        retained.iter().sum::<f64>() / retained.len() as f64
+    }
+}
+
 fn generate_csv_data(rows: usize, cols: usize, data_type: &str) -> Vec<Vec<String>> {
     let mut rng = rand::thread_rng();
     let mut data = Vec::new();
@@ -39,22 +136,143 @@ fn generate_csv_data(rows: usize, cols: usize, data_type: &str) -> Vec<Vec<Strin
     data
 }
 
-fn write_csv_to_string(data: &Vec<Vec<String>>) -> String {
+/// Controls how CSV text is read and written: which byte separates fields,
+/// which byte quotes a field, and whether reaching end of input inside an
+/// unterminated quoted field prints a warning (`strict`). Either way the
+/// partial field is kept as read up to end of input.
+#[derive(Debug, Clone, Copy)]
+struct Dialect {
+    delimiter: char,
+    quote: char,
+    strict: bool,
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Dialect { delimiter: ',', quote: '"', strict: false }
+    }
+}
+
+/// Quotes `field` per RFC 4180 if it contains the delimiter, the quote
+/// character, or a newline, doubling any embedded quote characters.
+fn write_csv_field(field: &str, dialect: &Dialect) -> String {
+    let needs_quoting = field.contains(dialect.delimiter)
+        || field.contains(dialect.quote)
+        || field.contains('\n')
+        || field.contains('\r');
+
+    if !needs_quoting {
+        return field.to_string();
+    }
+
+    let mut quoted = String::with_capacity(field.len() + 2);
+    quoted.push(dialect.quote);
+    for c in field.chars() {
+        if c == dialect.quote {
+            quoted.push(dialect.quote);
+        }
+        quoted.push(c);
+    }
+    quoted.push(dialect.quote);
+    quoted
+}
+
+fn write_csv_to_string_with_dialect(data: &Vec<Vec<String>>, dialect: &Dialect) -> String {
     let mut result = String::new();
     for row in data {
-        let row_str = row.join(",");
+        let row_str = row
+            .iter()
+            .map(|field| write_csv_field(field, dialect))
+            .collect::<Vec<_>>()
+            .join(&dialect.delimiter.to_string());
         result.push_str(&row_str);
         result.push('\n');
     }
     result
 }
 
-fn read_csv_from_string(csv_string: &str) -> Vec<Vec<String>> {
-    csv_string
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| line.split(',').map(|s| s.to_string()).collect())
-        .collect()
+/// Parses `csv_string` as RFC 4180 CSV: double-quoted fields may contain the
+/// delimiter, the quote character (escaped as a doubled quote, `""` -> `"`),
+/// and embedded newlines. A character-at-a-time scan is required (rather
+/// than splitting on `\n` first) since a quoted field can itself span
+/// multiple lines.
+fn read_csv_from_string_with_dialect(csv_string: &str, dialect: &Dialect) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut row_has_content = false;
+    let mut chars = csv_string.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == dialect.quote {
+                if chars.peek() == Some(&dialect.quote) {
+                    field.push(dialect.quote);
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+
+        if c == dialect.quote && field.is_empty() {
+            in_quotes = true;
+            row_has_content = true;
+        } else if c == dialect.delimiter {
+            row.push(std::mem::take(&mut field));
+            row_has_content = true;
+        } else if c == '\r' {
+            // Swallow; the paired '\n' (or end of input) ends the row.
+        } else if c == '\n' {
+            row.push(std::mem::take(&mut field));
+            rows.push(std::mem::take(&mut row));
+            row_has_content = false;
+        } else {
+            field.push(c);
+            row_has_content = true;
+        }
+    }
+
+    if dialect.strict && in_quotes {
+        eprintln!("Warning: CSV input ended inside an open quoted field");
+    }
+
+    if row_has_content || !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Parses `data` into delimiter-separated byte-record fields borrowed
+/// straight from the input (the approach qsv uses for speed), with no
+/// per-cell `String` allocation, so the `read` vs. `read_bytes` timings
+/// isolate parsing cost from allocation cost. Unlike
+/// `read_csv_from_string_with_dialect` this doesn't understand quoting -
+/// it's a fast path for the common unquoted case, not a drop-in
+/// replacement. Returns the total field count and an XOR checksum of field
+/// lengths so the optimizer can't elide the scan.
+fn read_csv_bytes_with_dialect(data: &[u8], dialect: &Dialect) -> (usize, u64) {
+    let delimiter = dialect.delimiter as u8;
+    let mut field_count = 0usize;
+    let mut checksum: u64 = 0;
+
+    for line in data.split(|&b| b == b'\n') {
+        if line.is_empty() {
+            continue;
+        }
+        for field in line.split(|&b| b == delimiter) {
+            field_count += 1;
+            checksum ^= field.len() as u64;
+        }
+    }
+
+    (field_count, checksum)
 }
 
 fn filter_csv_data(data: &Vec<Vec<String>>, filter_column: usize) -> Vec<Vec<String>> {
@@ -79,14 +297,38 @@ fn filter_csv_data(data: &Vec<Vec<String>>, filter_column: usize) -> Vec<Vec<Str
     filtered_data
 }
 
-fn aggregate_csv_data(data: &Vec<Vec<String>>) -> HashMap<String, HashMap<String, f64>> {
+/// Nearest-rank quantile of a value already sorted ascending: `idx = ceil(q
+/// * n) - 1`, clamped into `[0, n-1]` so `q=0.0` and `q=1.0` land on the
+/// first/last element exactly.
+fn quantile(sorted_values: &[f64], q: f64) -> f64 {
+    let n = sorted_values.len();
+    if n == 0 {
+        return 0.0;
+    }
+    let idx = ((q * n as f64).ceil() as isize - 1).clamp(0, n as isize - 1) as usize;
+    sorted_values[idx]
+}
+
+fn median(sorted_values: &[f64]) -> f64 {
+    let n = sorted_values.len();
+    if n == 0 {
+        return 0.0;
+    }
+    if n % 2 == 1 {
+        sorted_values[n / 2]
+    } else {
+        (sorted_values[n / 2 - 1] + sorted_values[n / 2]) / 2.0
+    }
+}
+
+fn aggregate_csv_data(data: &Vec<Vec<String>>, statistics: &[String]) -> HashMap<String, HashMap<String, f64>> {
     if data.is_empty() || data.len() < 2 {
         return HashMap::new();
     }
-    
+
     let headers = &data[0];
     let mut numeric_columns = Vec::new();
-    
+
     // Find numeric columns
     for col_idx in 0..headers.len() {
         let mut is_numeric = true;
@@ -102,21 +344,33 @@ fn aggregate_csv_data(data: &Vec<Vec<String>>) -> HashMap<String, HashMap<String
             numeric_columns.push(col_idx);
         }
     }
-    
+
     let mut aggregations = HashMap::new();
-    
+
     for &col_idx in &numeric_columns {
         let col_name = &headers[col_idx];
         let mut values = Vec::new();
-        
+
+        // Welford's online algorithm for variance: updated alongside
+        // collecting `values`, one pass, so a later stddev doesn't need to
+        // re-read the column.
+        let mut count = 0u64;
+        let mut mean = 0.0f64;
+        let mut m2 = 0.0f64;
+
         for row in &data[1..] {
             if col_idx < row.len() {
                 if let Ok(value) = row[col_idx].parse::<f64>() {
                     values.push(value);
+
+                    count += 1;
+                    let delta = value - mean;
+                    mean += delta / count as f64;
+                    m2 += delta * (value - mean);
                 }
             }
         }
-        
+
         if !values.is_empty() {
             let mut stats = HashMap::new();
             stats.insert("sum".to_string(), values.iter().sum());
@@ -124,14 +378,141 @@ fn aggregate_csv_data(data: &Vec<Vec<String>>) -> HashMap<String, HashMap<String
             stats.insert("min".to_string(), *values.iter().min_by(|a, b| a.partial_cmp(b).unwrap()).unwrap());
             stats.insert("max".to_string(), *values.iter().max_by(|a, b| a.partial_cmp(b).unwrap()).unwrap());
             stats.insert("count".to_string(), values.len() as f64);
-            
+
+            if !statistics.is_empty() {
+                let variance = if count > 0 { m2 / count as f64 } else { 0.0 };
+                let mut sorted_values = values.clone();
+                sorted_values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                for stat in statistics {
+                    match stat.as_str() {
+                        "stddev" => { stats.insert("stddev".to_string(), variance.sqrt()); }
+                        "median" => { stats.insert("median".to_string(), median(&sorted_values)); }
+                        "p25" => { stats.insert("p25".to_string(), quantile(&sorted_values, 0.25)); }
+                        "p75" => { stats.insert("p75".to_string(), quantile(&sorted_values, 0.75)); }
+                        "p90" => { stats.insert("p90".to_string(), quantile(&sorted_values, 0.90)); }
+                        "p99" => { stats.insert("p99".to_string(), quantile(&sorted_values, 0.99)); }
+                        _ => {}
+                    }
+                }
+            }
+
             aggregations.insert(col_name.clone(), stats);
         }
     }
-    
+
     aggregations
 }
 
+/// Which reduction a pivot group's accumulated value column collapses to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PivotAgg {
+    Sum,
+    Mean,
+    Count,
+    Min,
+    Max,
+}
+
+impl PivotAgg {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "mean" => PivotAgg::Mean,
+            "count" => PivotAgg::Count,
+            "min" => PivotAgg::Min,
+            "max" => PivotAgg::Max,
+            _ => PivotAgg::Sum,
+        }
+    }
+}
+
+/// A running reduction over one group's value-column numbers, updated one
+/// row at a time so the whole pivot is a single streaming pass over `data`.
+#[derive(Debug, Clone, Copy)]
+struct Accumulator {
+    sum: f64,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl Accumulator {
+    fn new() -> Self {
+        Accumulator { sum: 0.0, count: 0, min: f64::INFINITY, max: f64::NEG_INFINITY }
+    }
+
+    fn push(&mut self, value: f64) {
+        self.sum += value;
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn finalize(&self, agg: PivotAgg) -> f64 {
+        match agg {
+            PivotAgg::Sum => self.sum,
+            PivotAgg::Mean => if self.count > 0 { self.sum / self.count as f64 } else { 0.0 },
+            PivotAgg::Count => self.count as f64,
+            PivotAgg::Min => if self.count > 0 { self.min } else { 0.0 },
+            PivotAgg::Max => if self.count > 0 { self.max } else { 0.0 },
+        }
+    }
+}
+
+/// Group-by/pivot over `data`: partitions rows by the stringified values of
+/// `group_by` columns and accumulates `value_column` per group, reducing
+/// each group with `agg`. Returns one entry per distinct group key, keyed
+/// by the group values joined with `" | "` for readability in the JSON
+/// output. Groups come back in `HashMap` order, so their order is not
+/// defined.
+fn pivot_csv_data(
+    data: &Vec<Vec<String>>,
+    group_by: &[usize],
+    value_column: usize,
+    agg: PivotAgg,
+) -> HashMap<String, f64> {
+    if data.is_empty() || data.len() < 2 || group_by.is_empty() {
+        return HashMap::new();
+    }
+
+    let mut groups: HashMap<Vec<String>, Accumulator> = HashMap::new();
+
+    for row in &data[1..] {
+        if group_by.iter().any(|&idx| idx >= row.len()) || value_column >= row.len() {
+            continue;
+        }
+        let Ok(value) = row[value_column].parse::<f64>() else { continue };
+
+        let key: Vec<String> = group_by.iter().map(|&idx| row[idx].clone()).collect();
+        groups.entry(key).or_insert_with(Accumulator::new).push(value);
+    }
+
+    groups
+        .into_iter()
+        .map(|(key, acc)| (key.join(" | "), acc.finalize(agg)))
+        .collect()
+}
+
+/// Sanity-checks the RFC 4180 writer/parser round-trip before running the
+/// benchmark: writing then reading back cells containing the delimiter and
+/// an embedded newline must recover the original values exactly.
+fn verify_csv_round_trip(dialect: &Dialect) {
+    let original = vec![
+        vec!["a,b".to_string(), "line1\nline2".to_string()],
+        vec!["plain".to_string(), format!("has {} quote", dialect.quote)],
+    ];
+
+    let written = write_csv_to_string_with_dialect(&original, dialect);
+    let parsed = read_csv_from_string_with_dialect(&written, dialect);
+
+    if parsed != original {
+        eprintln!(
+            "Warning: CSV round-trip check failed: expected {:?}, got {:?}",
+            original, parsed
+        );
+    }
+}
+
 fn run_csv_processing_benchmark(config: &Value) -> Value {
     let parameters = &config["parameters"];
     
@@ -164,13 +545,60 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
         .collect();
     
     let iterations = parameters["iterations"].as_u64().unwrap_or(3) as usize;
-    
+
+    let dialect_config = &parameters["dialect"];
+    let dialect = Dialect {
+        delimiter: dialect_config["delimiter"]
+            .as_str()
+            .and_then(|s| s.chars().next())
+            .unwrap_or(','),
+        quote: dialect_config["quote"]
+            .as_str()
+            .and_then(|s| s.chars().next())
+            .unwrap_or('"'),
+        strict: dialect_config["strict"].as_bool().unwrap_or(false),
+    };
+
+    verify_csv_round_trip(&dialect);
+
+    let group_by: Vec<usize> = parameters["group_by"]
+        .as_array()
+        .unwrap_or(&Vec::new())
+        .iter()
+        .filter_map(|v| v.as_u64().map(|n| n as usize))
+        .collect();
+    let value_column = parameters["value_column"].as_u64().unwrap_or(0) as usize;
+    let pivot_agg = PivotAgg::from_str(parameters["agg"].as_str().unwrap_or("sum"));
+
+    let statistics: Vec<String> = parameters["statistics"]
+        .as_array()
+        .unwrap_or(&Vec::new())
+        .iter()
+        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+        .collect();
+
+    // Runs executed but excluded from every `*_times` vector, to burn off
+    // cold-start effects (allocator warm-up, CPU frequency scaling) before
+    // the recorded iterations begin.
+    let warmup_iterations = parameters["warmup_iterations"].as_u64().unwrap_or(0) as usize;
+    // Fraction of samples trimmed from each end of the sorted times before
+    // averaging; 0.0 (default) means no trimming.
+    let trim_percent = parameters["trim_percent"].as_f64().unwrap_or(0.0);
+
     let start_time = Instant::now();
     let mut test_cases = Vec::new();
     let mut all_read_times = Vec::new();
     let mut all_write_times = Vec::new();
     let mut all_filter_times = Vec::new();
     let mut all_aggregate_times = Vec::new();
+    let mut all_pivot_times = Vec::new();
+    let mut all_read_bytes_times = Vec::new();
+    let mut read_histogram = LatencyHistogram::new();
+    let mut read_bytes_histogram = LatencyHistogram::new();
+    let mut write_histogram = LatencyHistogram::new();
+    let mut filter_histogram = LatencyHistogram::new();
+    let mut aggregate_histogram = LatencyHistogram::new();
+    let mut pivot_histogram = LatencyHistogram::new();
     let mut total_tests = 0;
     let mut successful_tests = 0;
     let mut failed_tests = 0;
@@ -184,8 +612,34 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
                 let mut write_times = Vec::new();
                 let mut filter_times = Vec::new();
                 let mut aggregate_times = Vec::new();
+                let mut pivot_times = Vec::new();
+                let mut read_bytes_times = Vec::new();
                 let mut iterations_data = Vec::new();
-                
+
+                for _ in 0..warmup_iterations {
+                    let csv_data = generate_csv_data(rows, cols, data_type);
+                    if operations.contains(&"write".to_string()) {
+                        let _ = write_csv_to_string_with_dialect(&csv_data, &dialect);
+                    }
+                    if operations.contains(&"read".to_string()) {
+                        let csv_string = write_csv_to_string_with_dialect(&csv_data, &dialect);
+                        let _ = read_csv_from_string_with_dialect(&csv_string, &dialect);
+                    }
+                    if operations.contains(&"read_bytes".to_string()) {
+                        let csv_string = write_csv_to_string_with_dialect(&csv_data, &dialect);
+                        let _ = read_csv_bytes_with_dialect(csv_string.as_bytes(), &dialect);
+                    }
+                    if operations.contains(&"filter".to_string()) {
+                        let _ = filter_csv_data(&csv_data, 0);
+                    }
+                    if operations.contains(&"aggregate".to_string()) {
+                        let _ = aggregate_csv_data(&csv_data, &statistics);
+                    }
+                    if operations.contains(&"pivot".to_string()) {
+                        let _ = pivot_csv_data(&csv_data, &group_by, value_column, pivot_agg);
+                    }
+                }
+
                 for i in 0..iterations {
                     eprintln!("  Iteration {}/{}...", i + 1, iterations);
                     
@@ -204,12 +658,13 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
                     // Write operation
                     if operations.contains(&"write".to_string()) {
                         let start = Instant::now();
-                        let csv_string = write_csv_to_string(&csv_data);
+                        let csv_string = write_csv_to_string_with_dialect(&csv_data, &dialect);
                         let write_time = start.elapsed().as_secs_f64() * 1000.0;
                         
                         write_times.push(write_time);
                         all_write_times.push(write_time);
-                        
+                        write_histogram.record(write_time / 1000.0);
+
                         iteration_result["operations"]["write"] = json!({
                             "success": true,
                             "time_ms": write_time,
@@ -219,15 +674,16 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
                     
                     // Read operation
                     if operations.contains(&"read".to_string()) {
-                        let csv_string = write_csv_to_string(&csv_data);
-                        
+                        let csv_string = write_csv_to_string_with_dialect(&csv_data, &dialect);
+
                         let start = Instant::now();
-                        let read_data = read_csv_from_string(&csv_string);
+                        let read_data = read_csv_from_string_with_dialect(&csv_string, &dialect);
                         let read_time = start.elapsed().as_secs_f64() * 1000.0;
                         
                         read_times.push(read_time);
                         all_read_times.push(read_time);
-                        
+                        read_histogram.record(read_time / 1000.0);
+
                         iteration_result["operations"]["read"] = json!({
                             "success": true,
                             "time_ms": read_time,
@@ -235,6 +691,30 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
                         });
                     }
                     
+                    // Zero-copy byte-record read operation: parses the same
+                    // bytes as the `read` operation above, but yields
+                    // borrowed `&[u8]` field slices instead of owned
+                    // `String`s, isolating parsing cost from allocation
+                    // cost.
+                    if operations.contains(&"read_bytes".to_string()) {
+                        let csv_string = write_csv_to_string_with_dialect(&csv_data, &dialect);
+
+                        let start = Instant::now();
+                        let (field_count, checksum) = read_csv_bytes_with_dialect(csv_string.as_bytes(), &dialect);
+                        let read_bytes_time = start.elapsed().as_secs_f64() * 1000.0;
+
+                        read_bytes_times.push(read_bytes_time);
+                        all_read_bytes_times.push(read_bytes_time);
+                        read_bytes_histogram.record(read_bytes_time / 1000.0);
+
+                        iteration_result["operations"]["read_bytes"] = json!({
+                            "success": true,
+                            "time_ms": read_bytes_time,
+                            "field_count": field_count,
+                            "checksum": checksum
+                        });
+                    }
+
                     // Filter operation
                     if operations.contains(&"filter".to_string()) {
                         let start = Instant::now();
@@ -243,7 +723,8 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
                         
                         filter_times.push(filter_time);
                         all_filter_times.push(filter_time);
-                        
+                        filter_histogram.record(filter_time / 1000.0);
+
                         iteration_result["operations"]["filter"] = json!({
                             "success": true,
                             "time_ms": filter_time,
@@ -255,12 +736,13 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
                     // Aggregate operation
                     if operations.contains(&"aggregate".to_string()) {
                         let start = Instant::now();
-                        let aggregations = aggregate_csv_data(&csv_data);
+                        let aggregations = aggregate_csv_data(&csv_data, &statistics);
                         let aggregate_time = start.elapsed().as_secs_f64() * 1000.0;
                         
                         aggregate_times.push(aggregate_time);
                         all_aggregate_times.push(aggregate_time);
-                        
+                        aggregate_histogram.record(aggregate_time / 1000.0);
+
                         iteration_result["operations"]["aggregate"] = json!({
                             "success": true,
                             "time_ms": aggregate_time,
@@ -268,6 +750,23 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
                         });
                     }
                     
+                    // Pivot operation
+                    if operations.contains(&"pivot".to_string()) {
+                        let start = Instant::now();
+                        let groups = pivot_csv_data(&csv_data, &group_by, value_column, pivot_agg);
+                        let pivot_time = start.elapsed().as_secs_f64() * 1000.0;
+
+                        pivot_times.push(pivot_time);
+                        all_pivot_times.push(pivot_time);
+                        pivot_histogram.record(pivot_time / 1000.0);
+
+                        iteration_result["operations"]["pivot"] = json!({
+                            "success": true,
+                            "time_ms": pivot_time,
+                            "group_count": groups.len()
+                        });
+                    }
+
                     if success {
                         successful_tests += 1;
                     } else {
@@ -283,10 +782,12 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
                     "data_type": data_type,
                     "operations": operations,
                     "iterations": iterations_data,
-                    "avg_read_time": if read_times.is_empty() { 0.0 } else { read_times.iter().sum::<f64>() / read_times.len() as f64 },
-                    "avg_write_time": if write_times.is_empty() { 0.0 } else { write_times.iter().sum::<f64>() / write_times.len() as f64 },
-                    "avg_filter_time": if filter_times.is_empty() { 0.0 } else { filter_times.iter().sum::<f64>() / filter_times.len() as f64 },
-                    "avg_aggregate_time": if aggregate_times.is_empty() { 0.0 } else { aggregate_times.iter().sum::<f64>() / aggregate_times.len() as f64 }
+                    "avg_read_time": trimmed_mean(&read_times, trim_percent),
+                    "avg_write_time": trimmed_mean(&write_times, trim_percent),
+                    "avg_filter_time": trimmed_mean(&filter_times, trim_percent),
+                    "avg_aggregate_time": trimmed_mean(&aggregate_times, trim_percent),
+                    "avg_pivot_time": trimmed_mean(&pivot_times, trim_percent),
+                    "avg_read_bytes_time": trimmed_mean(&read_bytes_times, trim_percent)
                 });
                 
                 test_cases.push(test_case);
@@ -303,10 +804,20 @@ fn run_csv_processing_benchmark(config: &Value) -> Value {
             "total_tests": total_tests,
             "successful_tests": successful_tests,
             "failed_tests": failed_tests,
-            "avg_read_time": if all_read_times.is_empty() { 0.0 } else { all_read_times.iter().sum::<f64>() / all_read_times.len() as f64 },
-            "avg_write_time": if all_write_times.is_empty() { 0.0 } else { all_write_times.iter().sum::<f64>() / all_write_times.len() as f64 },
-            "avg_filter_time": if all_filter_times.is_empty() { 0.0 } else { all_filter_times.iter().sum::<f64>() / all_filter_times.len() as f64 },
-            "avg_aggregate_time": if all_aggregate_times.is_empty() { 0.0 } else { all_aggregate_times.iter().sum::<f64>() / all_aggregate_times.len() as f64 }
+            "avg_read_time": trimmed_mean(&all_read_times, trim_percent),
+            "avg_write_time": trimmed_mean(&all_write_times, trim_percent),
+            "avg_filter_time": trimmed_mean(&all_filter_times, trim_percent),
+            "avg_aggregate_time": trimmed_mean(&all_aggregate_times, trim_percent),
+            "avg_pivot_time": trimmed_mean(&all_pivot_times, trim_percent),
+            "avg_read_bytes_time": trimmed_mean(&all_read_bytes_times, trim_percent),
+            "latency_histograms": {
+                "read": read_histogram.to_json(),
+                "write": write_histogram.to_json(),
+                "filter": filter_histogram.to_json(),
+                "aggregate": aggregate_histogram.to_json(),
+                "pivot": pivot_histogram.to_json(),
+                "read_bytes": read_bytes_histogram.to_json()
+            }
         },
         "end_time": 0, // Placeholder
         "total_execution_time": total_execution_time
@@ -2,10 +2,46 @@ use std::env;
 use std::fs;
 use std::time::Instant;
 use std::collections::HashMap;
+use serde::Deserialize;
 use serde_json::{json, Value};
 use rand::Rng;
 use rand::seq::SliceRandom;
 
+// Typed counterparts of the `array_heavy` shape generated by
+// `generate_array_heavy_json`, used by the `to_value_vs_struct` operation to
+// compare generic `Value` parsing against typed deserialization.
+#[derive(Deserialize)]
+struct ArrayHeavyUser {
+    id: u64,
+    name: String,
+    email: String,
+    active: bool,
+}
+
+#[derive(Deserialize)]
+struct ArrayHeavyProduct {
+    id: u64,
+    name: String,
+    price: f64,
+    category: String,
+}
+
+#[derive(Deserialize)]
+struct ArrayHeavyOrder {
+    id: u64,
+    user_id: u64,
+    product_ids: Vec<u64>,
+    total: f64,
+    timestamp: String,
+}
+
+#[derive(Deserialize)]
+struct ArrayHeavyDocument {
+    users: Vec<ArrayHeavyUser>,
+    products: Vec<ArrayHeavyProduct>,
+    orders: Vec<ArrayHeavyOrder>,
+}
+
 // Optimized structures for better performance
 #[derive(Debug, Clone)]
 enum OptimizedValue {
@@ -177,6 +213,82 @@ fn generate_mixed_json(size: usize) -> Value {
     })
 }
 
+/// Renders `headers`/`rows` as a GitHub-flavored Markdown table: a header
+/// row, a `---` separator row, then one row per `rows` entry, with every
+/// column padded to its widest cell so the raw source lines up too.
+fn render_markdown_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.len());
+        }
+    }
+
+    let pad = |s: &str, w: usize| format!("{:<width$}", s, width = w);
+
+    let mut out = String::new();
+    out.push_str("| ");
+    out.push_str(&headers.iter().zip(&widths).map(|(h, &w)| pad(h, w)).collect::<Vec<_>>().join(" | "));
+    out.push_str(" |\n| ");
+    out.push_str(&widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>().join(" | "));
+    out.push_str(" |\n");
+    for row in rows {
+        out.push_str("| ");
+        out.push_str(&row.iter().zip(&widths).map(|(c, &w)| pad(c, w)).collect::<Vec<_>>().join(" | "));
+        out.push_str(" |\n");
+    }
+    out
+}
+
+/// Parses `json_string` as a stream of top-level `Deserializer` items rather
+/// than one `from_str::<Value>()` call, counting elements and dropping each
+/// streamed value as soon as it's counted instead of retaining the whole
+/// parsed tree for the rest of the benchmark iteration. Our test documents
+/// are single top-level JSON values, so this still materializes that one
+/// value same as `from_str` would -- serde_json's stable API doesn't expose
+/// a lower-level token walk -- but it isolates the "parse and immediately
+/// discard" cost from the "parse and keep for traversal" cost that the
+/// `parse` operation measures.
+fn stream_parse_json(json_string: &str) -> Result<usize, serde_json::Error> {
+    let mut count = 0;
+    for value in serde_json::Deserializer::from_str(json_string).into_iter::<Value>() {
+        let value = value?;
+        count += traverse_json(&value);
+    }
+    Ok(count)
+}
+
+/// Sorts a copy of `times` and discards the bottom and top `trim_pct`
+/// fraction of samples from each end (e.g. `0.1` for a 10% trim), returning
+/// the retained, still-sorted samples. `trim_pct` is clamped to `0.0..=0.49`
+/// so at least one sample always survives; `0.0` (the default) keeps every
+/// sample.
+fn trim_samples(times: &[f64], trim_pct: f64) -> Vec<f64> {
+    if times.is_empty() {
+        return Vec::new();
+    }
+    let mut sorted = times.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let trim_count = (sorted.len() as f64 * trim_pct.clamp(0.0, 0.49)).floor() as usize;
+    let end = sorted.len() - trim_count;
+    if trim_count < end {
+        sorted[trim_count..end].to_vec()
+    } else {
+        sorted
+    }
+}
+
+/// Mean over `times` after discarding the top/bottom `trim_pct` fraction via
+/// `trim_samples`. `0.0` for `trim_pct` is a plain mean.
+fn trimmed_mean(times: &[f64], trim_pct: f64) -> f64 {
+    let retained = trim_samples(times, trim_pct);
+    if retained.is_empty() {
+        0.0
+    } else {
+        retained.iter().sum::<f64>() / retained.len() as f64
+    }
+}
+
 // Optimized traversal function that avoids recursion overhead
 fn traverse_json(data: &Value) -> usize {
     let mut count = 0;
@@ -228,12 +340,22 @@ fn run_json_parsing_benchmark(config: &Value) -> Value {
         .collect();
     
     let iterations = parameters["iterations"].as_u64().unwrap_or(5) as usize;
-    
+
+    // Runs executed but excluded from every `*_times` vector, to burn off
+    // cold-start effects before the recorded iterations begin.
+    let warmup_iterations = parameters["warmup_iterations"].as_u64().unwrap_or(0) as usize;
+    // Fraction of samples trimmed from each end of the sorted times before
+    // averaging; 0.0 (default) means no trimming.
+    let trim_percent = parameters["trim_percent"].as_f64().unwrap_or(0.0);
+
     let start_time = Instant::now();
     let mut test_cases = Vec::new();
     let mut all_parse_times = Vec::new();
     let mut all_stringify_times = Vec::new();
     let mut all_traverse_times = Vec::new();
+    let mut all_stream_parse_times = Vec::new();
+    let mut all_value_vs_struct_value_times = Vec::new();
+    let mut all_value_vs_struct_struct_times = Vec::new();
     let mut total_tests = 0;
     let mut successful_tests = 0;
     let mut failed_tests = 0;
@@ -250,6 +372,9 @@ fn run_json_parsing_benchmark(config: &Value) -> Value {
             let mut parse_times = Vec::new();
             let mut stringify_times = Vec::new();
             let mut traverse_times = Vec::new();
+            let mut stream_parse_times = Vec::new();
+            let mut value_vs_struct_value_times = Vec::new();
+            let mut value_vs_struct_struct_times = Vec::new();
             let mut iterations_data = Vec::new();
             
             // Pre-allocate vectors for better performance
@@ -257,7 +382,34 @@ fn run_json_parsing_benchmark(config: &Value) -> Value {
             stringify_times.reserve(iterations);
             traverse_times.reserve(iterations);
             iterations_data.reserve(iterations);
-            
+
+            for _ in 0..warmup_iterations {
+                let json_data = match structure.as_str() {
+                    "flat" => generate_flat_json(*size),
+                    "nested" => generate_nested_json(*size, 5),
+                    "array_heavy" => generate_array_heavy_json(*size),
+                    "mixed" => generate_mixed_json(*size),
+                    _ => generate_flat_json(*size),
+                };
+                let json_string = serde_json::to_string(&json_data).unwrap();
+                if operations.contains(&"parse".to_string()) {
+                    let _ = serde_json::from_str::<Value>(&json_string);
+                }
+                if operations.contains(&"stringify".to_string()) {
+                    let _ = serde_json::to_string(&json_data);
+                }
+                if operations.contains(&"traverse".to_string()) {
+                    let _ = traverse_json(&json_data);
+                }
+                if operations.contains(&"stream_parse".to_string()) {
+                    let _ = stream_parse_json(&json_string);
+                }
+                if operations.contains(&"to_value_vs_struct".to_string()) && structure == "array_heavy" {
+                    let _ = serde_json::from_str::<Value>(&json_string);
+                    let _ = serde_json::from_str::<ArrayHeavyDocument>(&json_string);
+                }
+            }
+
             for i in 0..iterations {
                 eprintln!("  Iteration {}/{}...", i + 1, iterations);
                 
@@ -341,17 +493,83 @@ fn run_json_parsing_benchmark(config: &Value) -> Value {
                     let start = Instant::now();
                     let operation_count = traverse_json(&json_data);
                     let traverse_time = start.elapsed().as_secs_f64() * 1000.0;
-                    
+
                     traverse_times.push(traverse_time);
                     all_traverse_times.push(traverse_time);
-                    
+
                     iteration_result["operations"]["traverse"] = json!({
                         "success": true,
                         "time_ms": traverse_time,
                         "operations_count": operation_count
                     });
                 }
-                
+
+                // Streaming parse: counts elements without retaining the
+                // parsed tree across the whole iteration, as an
+                // approximation of memory behavior (see stream_parse_json's
+                // doc comment for why it's an approximation rather than a
+                // true zero-copy token walk).
+                if operations.contains(&"stream_parse".to_string()) {
+                    let start = Instant::now();
+                    match stream_parse_json(&json_string) {
+                        Ok(operation_count) => {
+                            let stream_parse_time = start.elapsed().as_secs_f64() * 1000.0;
+                            stream_parse_times.push(stream_parse_time);
+                            all_stream_parse_times.push(stream_parse_time);
+
+                            iteration_result["operations"]["stream_parse"] = json!({
+                                "success": true,
+                                "time_ms": stream_parse_time,
+                                "operations_count": operation_count
+                            });
+                        }
+                        Err(e) => {
+                            success = false;
+                            iteration_result["operations"]["stream_parse"] = json!({
+                                "success": false,
+                                "error": e.to_string()
+                            });
+                        }
+                    }
+                }
+
+                // Typed-struct vs generic Value parsing, only meaningful
+                // for the array_heavy shape since that's the only one with
+                // a matching #[derive(Deserialize)] struct defined above.
+                if operations.contains(&"to_value_vs_struct".to_string()) && structure == "array_heavy" {
+                    let value_start = Instant::now();
+                    let value_result = serde_json::from_str::<Value>(&json_string);
+                    let value_time = value_start.elapsed().as_secs_f64() * 1000.0;
+
+                    let struct_start = Instant::now();
+                    let struct_result = serde_json::from_str::<ArrayHeavyDocument>(&json_string);
+                    let struct_time = struct_start.elapsed().as_secs_f64() * 1000.0;
+
+                    match (value_result, struct_result) {
+                        (Ok(_), Ok(_)) => {
+                            value_vs_struct_value_times.push(value_time);
+                            value_vs_struct_struct_times.push(struct_time);
+                            all_value_vs_struct_value_times.push(value_time);
+                            all_value_vs_struct_struct_times.push(struct_time);
+
+                            iteration_result["operations"]["to_value_vs_struct"] = json!({
+                                "success": true,
+                                "value_time_ms": value_time,
+                                "struct_time_ms": struct_time,
+                                "speedup": if struct_time > 0.0 { value_time / struct_time } else { 0.0 }
+                            });
+                        }
+                        (value_result, struct_result) => {
+                            success = false;
+                            iteration_result["operations"]["to_value_vs_struct"] = json!({
+                                "success": false,
+                                "value_error": value_result.err().map(|e| e.to_string()),
+                                "struct_error": struct_result.err().map(|e| e.to_string())
+                            });
+                        }
+                    }
+                }
+
                 if success {
                     successful_tests += 1;
                 } else {
@@ -362,18 +580,13 @@ fn run_json_parsing_benchmark(config: &Value) -> Value {
             }
             
             // Calculate averages for this test case
-            let avg_parse_time = if !parse_times.is_empty() {
-                parse_times.iter().sum::<f64>() / parse_times.len() as f64
-            } else { 0.0 };
-            
-            let avg_stringify_time = if !stringify_times.is_empty() {
-                stringify_times.iter().sum::<f64>() / stringify_times.len() as f64
-            } else { 0.0 };
-            
-            let avg_traverse_time = if !traverse_times.is_empty() {
-                traverse_times.iter().sum::<f64>() / traverse_times.len() as f64
-            } else { 0.0 };
-            
+            let avg_parse_time = trimmed_mean(&parse_times, trim_percent);
+            let avg_stringify_time = trimmed_mean(&stringify_times, trim_percent);
+            let avg_traverse_time = trimmed_mean(&traverse_times, trim_percent);
+            let avg_stream_parse_time = trimmed_mean(&stream_parse_times, trim_percent);
+            let avg_value_vs_struct_value_time = trimmed_mean(&value_vs_struct_value_times, trim_percent);
+            let avg_value_vs_struct_struct_time = trimmed_mean(&value_vs_struct_struct_times, trim_percent);
+
             let test_case = json!({
                 "json_size": size,
                 "structure_type": structure,
@@ -381,7 +594,10 @@ fn run_json_parsing_benchmark(config: &Value) -> Value {
                 "iterations": iterations_data,
                 "avg_parse_time": avg_parse_time,
                 "avg_stringify_time": avg_stringify_time,
-                "avg_traverse_time": avg_traverse_time
+                "avg_traverse_time": avg_traverse_time,
+                "avg_stream_parse_time": avg_stream_parse_time,
+                "avg_value_vs_struct_value_time": avg_value_vs_struct_value_time,
+                "avg_value_vs_struct_struct_time": avg_value_vs_struct_struct_time
             });
             
             test_cases.push(test_case);
@@ -389,18 +605,13 @@ fn run_json_parsing_benchmark(config: &Value) -> Value {
     }
     
     // Calculate overall summary
-    let avg_parse_time = if !all_parse_times.is_empty() {
-        all_parse_times.iter().sum::<f64>() / all_parse_times.len() as f64
-    } else { 0.0 };
-    
-    let avg_stringify_time = if !all_stringify_times.is_empty() {
-        all_stringify_times.iter().sum::<f64>() / all_stringify_times.len() as f64
-    } else { 0.0 };
-    
-    let avg_traverse_time = if !all_traverse_times.is_empty() {
-        all_traverse_times.iter().sum::<f64>() / all_traverse_times.len() as f64
-    } else { 0.0 };
-    
+    let avg_parse_time = trimmed_mean(&all_parse_times, trim_percent);
+    let avg_stringify_time = trimmed_mean(&all_stringify_times, trim_percent);
+    let avg_traverse_time = trimmed_mean(&all_traverse_times, trim_percent);
+    let avg_stream_parse_time = trimmed_mean(&all_stream_parse_times, trim_percent);
+    let avg_value_vs_struct_value_time = trimmed_mean(&all_value_vs_struct_value_times, trim_percent);
+    let avg_value_vs_struct_struct_time = trimmed_mean(&all_value_vs_struct_struct_times, trim_percent);
+
     let total_execution_time = start_time.elapsed().as_secs_f64();
     
     json!({
@@ -412,7 +623,10 @@ fn run_json_parsing_benchmark(config: &Value) -> Value {
             "failed_tests": failed_tests,
             "avg_parse_time": avg_parse_time,
             "avg_stringify_time": avg_stringify_time,
-            "avg_traverse_time": avg_traverse_time
+            "avg_traverse_time": avg_traverse_time,
+            "avg_stream_parse_time": avg_stream_parse_time,
+            "avg_value_vs_struct_value_time": avg_value_vs_struct_value_time,
+            "avg_value_vs_struct_struct_time": avg_value_vs_struct_struct_time
         },
         "end_time": 0, // Placeholder
         "total_execution_time": total_execution_time
@@ -444,6 +658,27 @@ fn main() {
         }
     };
     
+    let output_format = config["parameters"]["output_format"].as_str().unwrap_or("json").to_string();
     let results = run_json_parsing_benchmark(&config);
-    println!("{}", serde_json::to_string_pretty(&results).unwrap());
+
+    if output_format == "markdown" {
+        let headers = ["Structure", "Size", "Avg Parse (ms)", "Avg Stringify (ms)", "Avg Traverse (ms)"];
+        let rows: Vec<Vec<String>> = results["test_cases"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tc| {
+                vec![
+                    tc["structure_type"].as_str().unwrap_or("").to_string(),
+                    tc["json_size"].to_string(),
+                    format!("{:.4}", tc["avg_parse_time"].as_f64().unwrap_or(0.0)),
+                    format!("{:.4}", tc["avg_stringify_time"].as_f64().unwrap_or(0.0)),
+                    format!("{:.4}", tc["avg_traverse_time"].as_f64().unwrap_or(0.0)),
+                ]
+            })
+            .collect();
+        print!("{}", render_markdown_table(&headers, &rows));
+    } else {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
 }
\ No newline at end of file
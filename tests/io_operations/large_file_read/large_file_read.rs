@@ -3,7 +3,10 @@ use std::env;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::Path;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use rand::prelude::*;
 use tempfile::TempDir;
@@ -15,6 +18,9 @@ struct ReadResult {
     throughput_mbps: f64,
     chunk_count: Option<u32>,
     avg_chunk_size: Option<f64>,
+    iops: Option<f64>,
+    page_faults: Option<u64>,
+    note: Option<String>,
 }
 
 fn generate_test_file(file_path: &Path, size_bytes: u64) -> Result<(), Box<dyn std::error::Error>> {
@@ -72,6 +78,9 @@ fn read_file_sequential(file_path: &Path, buffer_size: usize) -> Result<ReadResu
         throughput_mbps,
         chunk_count: None,
         avg_chunk_size: None,
+        iops: None,
+        page_faults: None,
+        note: None,
     })
 }
 
@@ -110,13 +119,404 @@ fn read_file_chunked(file_path: &Path, buffer_size: usize) -> Result<ReadResult,
         throughput_mbps,
         chunk_count: Some(chunk_count),
         avg_chunk_size: Some(avg_chunk_size),
+        iops: None,
+        page_faults: None,
+        note: None,
     })
 }
 
-fn get_memory_usage() -> f64 {
-    // Simple memory usage approximation for Rust
-    // In a real implementation, you might use external crates for more accurate measurement
-    0.0
+/// Reads `file` at `offset` into `buf`. On Unix this is `pread` (`read_at`)
+/// and leaves the file's position alone; on Windows it is `seek_read`, which
+/// also moves the file's position to the end of the read.
+#[cfg(unix)]
+fn positioned_read(file: &File, buf: &mut [u8], offset: u64) -> std::io::Result<usize> {
+    use std::os::unix::fs::FileExt;
+    file.read_at(buf, offset)
+}
+
+#[cfg(windows)]
+fn positioned_read(file: &File, buf: &mut [u8], offset: u64) -> std::io::Result<usize> {
+    use std::os::windows::fs::FileExt;
+    file.seek_read(buf, offset)
+}
+
+/// Fisher-Yates shuffle driven by the repo's usual LCG, so the access order
+/// is randomized but reproducible across runs.
+fn shuffle_offsets(values: &mut [u64], seed: u32) {
+    let mut seed = seed;
+    for i in (1..values.len()).rev() {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        let j = (seed as usize) % (i + 1);
+        values.swap(i, j);
+    }
+}
+
+/// Issues positioned reads against a shuffled list of offsets, one per whole
+/// `buffer_size` block of the file (a trailing partial block is not read,
+/// except that a file smaller than one block is read once from offset 0),
+/// so the access pattern defeats the kernel's sequential
+/// readahead instead of benefiting from it. Reports IOPS (blocks/sec)
+/// alongside the usual throughput figure.
+fn read_file_random(file_path: &Path, buffer_size: usize) -> Result<ReadResult, Box<dyn std::error::Error>> {
+    let file = File::open(file_path)?;
+    let file_len = file.metadata()?.len();
+    let block_size = buffer_size as u64;
+    let block_count = (file_len / block_size).max(1);
+
+    let mut offsets: Vec<u64> = (0..block_count).map(|i| i * block_size).collect();
+    shuffle_offsets(&mut offsets, 42);
+
+    let mut buffer = vec![0u8; buffer_size];
+    let mut total_bytes = 0u64;
+    let start_time = Instant::now();
+    for &offset in &offsets {
+        total_bytes += positioned_read(&file, &mut buffer, offset)? as u64;
+    }
+    let read_time = start_time.elapsed().as_secs_f64();
+
+    let throughput_mbps = if read_time > 0.0 {
+        (total_bytes as f64 / (1024.0 * 1024.0)) / read_time
+    } else {
+        0.0
+    };
+    let iops = if read_time > 0.0 {
+        offsets.len() as f64 / read_time
+    } else {
+        0.0
+    };
+
+    Ok(ReadResult {
+        read_time: read_time * 1000.0,
+        bytes_read: total_bytes,
+        throughput_mbps,
+        chunk_count: Some(offsets.len() as u32),
+        avg_chunk_size: None,
+        iops: Some(iops),
+        page_faults: None,
+        note: None,
+    })
+}
+
+/// Minor page faults charged to this process so far, read from field 10 of
+/// `/proc/self/stat` (the `comm` field can itself contain spaces and
+/// parens, so parsing starts after the last `)` rather than splitting the
+/// whole line on whitespace).
+#[cfg(target_os = "linux")]
+fn read_minor_faults() -> u64 {
+    let contents = match std::fs::read_to_string("/proc/self/stat") {
+        Ok(c) => c,
+        Err(_) => return 0,
+    };
+    let after_comm = match contents.rfind(')') {
+        Some(idx) => &contents[idx + 1..],
+        None => return 0,
+    };
+    after_comm
+        .split_whitespace()
+        .nth(7) // state=0, ... minflt is the 10th /proc/[pid]/stat field overall
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_minor_faults() -> u64 {
+    0
+}
+
+/// Maps the file and touches one byte per page instead of calling `read`,
+/// so the timing reflects page-fault-driven access rather than syscall
+/// overhead. `page_faults` is the minor-fault delta observed during the
+/// walk, which is the number of pages the kernel actually had to fault in
+/// (as opposed to `bytes_read / page_size`, which would just restate the
+/// file size).
+fn read_file_mmap(file_path: &Path) -> Result<ReadResult, Box<dyn std::error::Error>> {
+    let file = File::open(file_path)?;
+    let file_len = file.metadata()?.len();
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) }.max(4096) as u64;
+
+    let faults_before = read_minor_faults();
+    let start_time = Instant::now();
+
+    let mmap = unsafe { memmap2::Mmap::map(&file)? };
+    let mut acc: u64 = 0;
+    let mut offset = 0u64;
+    while offset < file_len {
+        acc = acc.wrapping_add(std::hint::black_box(mmap[offset as usize]) as u64);
+        offset += page_size;
+    }
+    std::hint::black_box(acc);
+
+    let read_time = start_time.elapsed().as_secs_f64();
+    let page_faults = read_minor_faults().saturating_sub(faults_before);
+
+    let throughput_mbps = if read_time > 0.0 {
+        (file_len as f64 / (1024.0 * 1024.0)) / read_time
+    } else {
+        0.0
+    };
+
+    Ok(ReadResult {
+        read_time: read_time * 1000.0,
+        bytes_read: file_len,
+        throughput_mbps,
+        chunk_count: None,
+        avg_chunk_size: None,
+        iops: None,
+        page_faults: Some(page_faults),
+        note: None,
+    })
+}
+
+/// A `posix_memalign`-backed buffer, since `O_DIRECT` requires reads to
+/// land on a block-size-aligned address and `Vec<u8>` makes no such
+/// guarantee.
+struct AlignedBuffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl AlignedBuffer {
+    fn new(len: usize, align: usize) -> Self {
+        let mut ptr: *mut libc::c_void = std::ptr::null_mut();
+        let ret = unsafe { libc::posix_memalign(&mut ptr, align, len) };
+        assert_eq!(ret, 0, "posix_memalign failed");
+        AlignedBuffer { ptr: ptr as *mut u8, len }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl Drop for AlignedBuffer {
+    fn drop(&mut self) {
+        unsafe { libc::free(self.ptr as *mut libc::c_void) };
+    }
+}
+
+/// Opens the file with `O_DIRECT` to bypass the page cache, so the
+/// measured throughput reflects the underlying device rather than how much
+/// of the file is already cached in RAM. Not every filesystem supports
+/// `O_DIRECT` (tmpfs, some overlay/network filesystems don't), and the
+/// flag doesn't exist outside Linux at all, so both cases are reported as
+/// a skipped-but-successful result with a `note` rather than an error.
+#[cfg(target_os = "linux")]
+fn read_file_direct(file_path: &Path, buffer_size: usize) -> Result<ReadResult, Box<dyn std::error::Error>> {
+    use std::fs::OpenOptions;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let block_size = 4096usize;
+    let aligned_buffer_size = ((buffer_size + block_size - 1) / block_size) * block_size;
+
+    let file = match OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_DIRECT)
+        .open(file_path)
+    {
+        Ok(f) => f,
+        Err(e) => {
+            return Ok(ReadResult {
+                read_time: 0.0,
+                bytes_read: 0,
+                throughput_mbps: 0.0,
+                chunk_count: None,
+                avg_chunk_size: None,
+                iops: None,
+                page_faults: None,
+                note: Some(format!("O_DIRECT unavailable on this filesystem: {}", e)),
+            });
+        }
+    };
+
+    let mut aligned = AlignedBuffer::new(aligned_buffer_size, block_size);
+    let mut total_bytes = 0u64;
+    let start_time = Instant::now();
+    let mut offset = 0u64;
+    loop {
+        match positioned_read(&file, aligned.as_mut_slice(), offset) {
+            Ok(0) => break,
+            Ok(n) => {
+                total_bytes += n as u64;
+                offset += n as u64;
+            }
+            Err(e) => {
+                return Ok(ReadResult {
+                    read_time: start_time.elapsed().as_secs_f64() * 1000.0,
+                    bytes_read: total_bytes,
+                    throughput_mbps: 0.0,
+                    chunk_count: None,
+                    avg_chunk_size: None,
+                    iops: None,
+                    page_faults: None,
+                    note: Some(format!("O_DIRECT read failed: {}", e)),
+                });
+            }
+        }
+    }
+    let read_time = start_time.elapsed().as_secs_f64();
+    let throughput_mbps = if read_time > 0.0 {
+        (total_bytes as f64 / (1024.0 * 1024.0)) / read_time
+    } else {
+        0.0
+    };
+
+    Ok(ReadResult {
+        read_time: read_time * 1000.0,
+        bytes_read: total_bytes,
+        throughput_mbps,
+        chunk_count: None,
+        avg_chunk_size: None,
+        iops: None,
+        page_faults: None,
+        note: None,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_file_direct(_file_path: &Path, _buffer_size: usize) -> Result<ReadResult, Box<dyn std::error::Error>> {
+    Ok(ReadResult {
+        read_time: 0.0,
+        bytes_read: 0,
+        throughput_mbps: 0.0,
+        chunk_count: None,
+        avg_chunk_size: None,
+        iops: None,
+        page_faults: None,
+        note: Some("O_DIRECT is only supported on Linux".to_string()),
+    })
+}
+
+/// Current resident set size in bytes. On Linux this reads field 2 (resident
+/// pages) of `/proc/self/statm`; other platforms don't have an equivalent
+/// dependency-free path here, so they report 0 rather than a fabricated
+/// number.
+#[cfg(target_os = "linux")]
+fn read_rss_bytes() -> u64 {
+    let contents = match std::fs::read_to_string("/proc/self/statm") {
+        Ok(c) => c,
+        Err(_) => return 0,
+    };
+    let resident_pages: u64 = contents
+        .split_whitespace()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) }.max(0) as u64;
+    resident_pages * page_size
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_rss_bytes() -> u64 {
+    0
+}
+
+/// `VmHWM` from `/proc/self/status`, the kernel's own lifetime high-water
+/// mark for this process's resident memory, in bytes.
+#[cfg(target_os = "linux")]
+fn read_vm_hwm_bytes() -> u64 {
+    let contents = match std::fs::read_to_string("/proc/self/status") {
+        Ok(c) => c,
+        Err(_) => return 0,
+    };
+    contents
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))
+        .and_then(|rest| rest.trim().trim_end_matches(" kB").trim().parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+        .unwrap_or(0)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_vm_hwm_bytes() -> u64 {
+    0
+}
+
+/// A single read can be too fast for a before/after RSS reading to ever
+/// observe its transient peak, so this samples RSS from a background thread
+/// every millisecond while `work` runs and returns `(result, peak_rss_bytes)`.
+fn measure_peak_rss<T>(work: impl FnOnce() -> T) -> (T, u64) {
+    let stop = Arc::new(AtomicBool::new(false));
+    let peak = Arc::new(AtomicU64::new(read_rss_bytes()));
+
+    let sampler_stop = Arc::clone(&stop);
+    let sampler_peak = Arc::clone(&peak);
+    let sampler = thread::spawn(move || {
+        while !sampler_stop.load(Ordering::Relaxed) {
+            sampler_peak.fetch_max(read_rss_bytes(), Ordering::Relaxed);
+            thread::sleep(Duration::from_millis(1));
+        }
+    });
+
+    let result = work();
+
+    peak.fetch_max(read_rss_bytes(), Ordering::Relaxed);
+    stop.store(true, Ordering::Relaxed);
+    sampler.join().ok();
+
+    (result, peak.load(Ordering::Relaxed))
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p / 100.0) * (sorted.len() as f64 - 1.0);
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+    }
+}
+
+/// Hyperfine-style summary of a sample vector: mean, sample stddev,
+/// median, min/max, and coefficient of variation, plus IQR-based outlier
+/// detection (anything outside `Q1 - 1.5*IQR .. Q3 + 1.5*IQR`). Flags
+/// separately whether the very first sample is itself an outlier, since
+/// that specifically points at a cold-cache/warmup artifact rather than
+/// ordinary noise.
+fn compute_statistics(samples: &[f64]) -> Value {
+    if samples.is_empty() {
+        return json!(null);
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len();
+
+    let mean = sorted.iter().sum::<f64>() / n as f64;
+    let stddev = if n > 1 {
+        let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        variance.sqrt()
+    } else {
+        0.0
+    };
+
+    let q1 = percentile(&sorted, 25.0);
+    let q3 = percentile(&sorted, 75.0);
+    let iqr = q3 - q1;
+    let lower_fence = q1 - 1.5 * iqr;
+    let upper_fence = q3 + 1.5 * iqr;
+
+    let outliers: Vec<f64> = samples
+        .iter()
+        .copied()
+        .filter(|&v| v < lower_fence || v > upper_fence)
+        .collect();
+    let first_sample_is_outlier = samples[0] < lower_fence || samples[0] > upper_fence;
+
+    json!({
+        "mean": mean,
+        "stddev": stddev,
+        "median": percentile(&sorted, 50.0),
+        "min": sorted[0],
+        "max": sorted[n - 1],
+        "coefficient_of_variation": if mean != 0.0 { stddev / mean } else { 0.0 },
+        "outliers": outliers,
+        "first_sample_is_outlier": first_sample_is_outlier
+    })
 }
 
 fn run_large_file_read_benchmark(config: &Value) -> Result<Value, Box<dyn std::error::Error>> {
@@ -190,18 +590,24 @@ fn run_large_file_read_benchmark(config: &Value) -> Result<Value, Box<dyn std::e
                 for i in 0..iterations {
                     eprintln!("  Iteration {}/{}...", i + 1, iterations);
                     total_tests += 1;
-                    
-                    match perform_read_test(&test_file_path, *buffer_size, pattern) {
+
+                    let baseline_rss = read_rss_bytes();
+                    let (read_outcome, peak_rss) =
+                        measure_peak_rss(|| perform_read_test(&test_file_path, *buffer_size, pattern));
+
+                    match read_outcome {
                         Ok(read_result) => {
-                            let memory_usage = get_memory_usage();
+                            let memory_usage = peak_rss as f64 / (1024.0 * 1024.0);
+                            let memory_delta = peak_rss.saturating_sub(baseline_rss) as f64 / (1024.0 * 1024.0);
                             peak_memory = peak_memory.max(memory_usage);
-                            
+
                             let mut iteration_result = json!({
                                 "iteration": i + 1,
                                 "read_time": read_result.read_time,
                                 "bytes_read": read_result.bytes_read,
                                 "throughput_mbps": read_result.throughput_mbps,
                                 "memory_used": memory_usage,
+                                "memory_delta": memory_delta,
                                 "io_wait_time": read_result.read_time
                             });
                             
@@ -209,7 +615,16 @@ fn run_large_file_read_benchmark(config: &Value) -> Result<Value, Box<dyn std::e
                                 iteration_result["chunk_count"] = json!(chunk_count);
                                 iteration_result["avg_chunk_size"] = json!(avg_chunk_size);
                             }
-                            
+                            if let Some(iops) = read_result.iops {
+                                iteration_result["iops"] = json!(iops);
+                            }
+                            if let Some(page_faults) = read_result.page_faults {
+                                iteration_result["page_faults"] = json!(page_faults);
+                            }
+                            if let Some(note) = &read_result.note {
+                                iteration_result["note"] = json!(note);
+                            }
+
                             iterations_array.push(iteration_result);
                             read_times.push(read_result.read_time);
                             throughputs.push(read_result.throughput_mbps);
@@ -237,7 +652,11 @@ fn run_large_file_read_benchmark(config: &Value) -> Result<Value, Box<dyn std::e
                     test_case["avg_read_time"] = json!(avg_read_time);
                     test_case["avg_throughput"] = json!(avg_throughput);
                     test_case["memory_efficiency"] = json!(memory_efficiency);
-                    
+                    test_case["statistics"] = json!({
+                        "read_time": compute_statistics(&read_times),
+                        "throughput": compute_statistics(&throughputs)
+                    });
+
                     all_read_times.extend(&read_times);
                     all_throughputs.extend(&throughputs);
                 }
@@ -274,40 +693,158 @@ fn run_large_file_read_benchmark(config: &Value) -> Result<Value, Box<dyn std::e
             "failed_tests": failed_tests,
             "avg_read_time": avg_read_time,
             "avg_throughput": avg_throughput,
-            "peak_memory_usage": peak_memory
+            "peak_memory_usage": peak_memory,
+            "process_peak_rss_mb": read_vm_hwm_bytes() as f64 / (1024.0 * 1024.0),
+            "statistics": {
+                "read_time": compute_statistics(&all_read_times),
+                "throughput": compute_statistics(&all_throughputs)
+            }
         }
     }))
 }
 
+/// Renders `result` as a GitHub-flavored Markdown table, one row per test
+/// case plus a bolded summary row, so it can be pasted directly into a PR
+/// or README instead of squinting at pretty-printed JSON. The `---:`
+/// header separators right-align the numeric columns per the Markdown
+/// spec.
+fn render_markdown(result: &Value) -> String {
+    let mut out = String::new();
+    out.push_str("| File Size | Buffer | Pattern | Avg Read (ms) | Avg Throughput (MB/s) | Throughput Stddev |\n");
+    out.push_str("|---:|---:|---|---:|---:|---:|\n");
+
+    if let Some(cases) = result.get("test_cases").and_then(|v| v.as_array()) {
+        for case in cases {
+            let file_size = case.get("file_size").and_then(|v| v.as_u64()).unwrap_or(0);
+            let buffer_size = case.get("buffer_size").and_then(|v| v.as_u64()).unwrap_or(0);
+            let pattern = case.get("read_pattern").and_then(|v| v.as_str()).unwrap_or("");
+            let avg_read_time = case.get("avg_read_time").and_then(|v| v.as_f64()).unwrap_or(0.0);
+            let avg_throughput = case.get("avg_throughput").and_then(|v| v.as_f64()).unwrap_or(0.0);
+            let stddev = case
+                .get("statistics")
+                .and_then(|s| s.get("throughput"))
+                .and_then(|s| s.get("stddev"))
+                .and_then(|v| v.as_f64())
+                .unwrap_or(0.0);
+            out.push_str(&format!(
+                "| {} | {} | {} | {:.3} | {:.3} | {:.3} |\n",
+                file_size, buffer_size, pattern, avg_read_time, avg_throughput, stddev
+            ));
+        }
+    }
+
+    if let Some(summary) = result.get("summary") {
+        let avg_read_time = summary.get("avg_read_time").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let avg_throughput = summary.get("avg_throughput").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let stddev = summary
+            .get("statistics")
+            .and_then(|s| s.get("throughput"))
+            .and_then(|s| s.get("stddev"))
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0);
+        out.push_str(&format!(
+            "| **Summary** | | | **{:.3}** | **{:.3}** | **{:.3}** |\n",
+            avg_read_time, avg_throughput, stddev
+        ));
+    }
+
+    out
+}
+
+/// Renders `result` as CSV, one row per test case plus a trailing summary
+/// row, for spreadsheet import.
+fn render_csv(result: &Value) -> String {
+    let mut out = String::new();
+    out.push_str("file_size,buffer_size,read_pattern,avg_read_time_ms,avg_throughput_mbps,throughput_stddev\n");
+
+    if let Some(cases) = result.get("test_cases").and_then(|v| v.as_array()) {
+        for case in cases {
+            let file_size = case.get("file_size").and_then(|v| v.as_u64()).unwrap_or(0);
+            let buffer_size = case.get("buffer_size").and_then(|v| v.as_u64()).unwrap_or(0);
+            let pattern = case.get("read_pattern").and_then(|v| v.as_str()).unwrap_or("");
+            let avg_read_time = case.get("avg_read_time").and_then(|v| v.as_f64()).unwrap_or(0.0);
+            let avg_throughput = case.get("avg_throughput").and_then(|v| v.as_f64()).unwrap_or(0.0);
+            let stddev = case
+                .get("statistics")
+                .and_then(|s| s.get("throughput"))
+                .and_then(|s| s.get("stddev"))
+                .and_then(|v| v.as_f64())
+                .unwrap_or(0.0);
+            out.push_str(&format!(
+                "{},{},{},{:.3},{:.3},{:.3}\n",
+                file_size, buffer_size, pattern, avg_read_time, avg_throughput, stddev
+            ));
+        }
+    }
+
+    if let Some(summary) = result.get("summary") {
+        let avg_read_time = summary.get("avg_read_time").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let avg_throughput = summary.get("avg_throughput").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let stddev = summary
+            .get("statistics")
+            .and_then(|s| s.get("throughput"))
+            .and_then(|s| s.get("stddev"))
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0);
+        out.push_str(&format!(
+            "summary,,,{:.3},{:.3},{:.3}\n",
+            avg_read_time, avg_throughput, stddev
+        ));
+    }
+
+    out
+}
+
 fn perform_read_test(file_path: &Path, buffer_size: usize, pattern: &str) -> Result<ReadResult, Box<dyn std::error::Error>> {
     match pattern {
         "sequential" => read_file_sequential(file_path, buffer_size),
         "chunked" => read_file_chunked(file_path, buffer_size),
+        "random" => read_file_random(file_path, buffer_size),
+        "mmap" => read_file_mmap(file_path),
+        "direct" => read_file_direct(file_path, buffer_size),
         _ => Err(format!("Unknown read pattern: {}", pattern).into()),
     }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
-    
-    if args.len() != 2 {
-        eprintln!("Usage: {} <input_file>", args[0]);
+
+    if args.len() < 2 {
+        eprintln!("Usage: {} <input_file> [--format json|markdown|csv]", args[0]);
         std::process::exit(1);
     }
-    
+
     let input_file = &args[1];
-    
+
+    let mut format: Option<String> = None;
+    let mut i = 2;
+    while i < args.len() {
+        if args[i] == "--format" && i + 1 < args.len() {
+            format = Some(args[i + 1].clone());
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
     // Read and parse input configuration
     let config_content = std::fs::read_to_string(input_file)?;
     let config: Value = serde_json::from_str(&config_content)?;
-    
+
     let parameters = config.get("parameters")
         .ok_or("Missing 'parameters' in configuration")?;
-    
+
+    let format = format
+        .or_else(|| config.get("format").and_then(|v| v.as_str()).map(|s| s.to_string()))
+        .unwrap_or_else(|| "json".to_string());
+
     let result = run_large_file_read_benchmark(parameters)?;
-    
-    // Output results as JSON
-    println!("{}", serde_json::to_string_pretty(&result)?);
-    
+
+    match format.as_str() {
+        "markdown" => println!("{}", render_markdown(&result)),
+        "csv" => println!("{}", render_csv(&result)),
+        _ => println!("{}", serde_json::to_string_pretty(&result)?),
+    }
+
     Ok(())
 }
\ No newline at end of file
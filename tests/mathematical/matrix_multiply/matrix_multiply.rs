@@ -1,5 +1,81 @@
+use std::env;
+use std::fs;
 use std::time::Instant;
 use rand::{thread_rng, Rng};
+use serde::Deserialize;
+use serde_json;
+
+#[derive(Deserialize)]
+struct Config {
+    parameters: Parameters,
+}
+
+#[derive(Deserialize)]
+struct Parameters {
+    size: Option<usize>,
+    tile_size: Option<usize>,
+    /// Which multiply strategies to run and time, in order: `"naive"` is the
+    /// textbook i-j-k loop, `"transposed"` pre-transposes `b` so the inner
+    /// loop reads two contiguous rows, `"blocked"` additionally tiles the
+    /// loop into `tile_size x tile_size` blocks. Defaults to running all
+    /// three so the polyglot comparison shows memory-hierarchy effects
+    /// rather than only the naive kernel.
+    algorithms: Option<Vec<String>>,
+}
+
+/// A log-spaced latency histogram: `NUM_BUCKETS` edges spanning `MIN_SECONDS`
+/// to `MAX_SECONDS` on a log scale, so a handful of buckets covers latencies
+/// from microseconds to seconds without needing to know the scale in
+/// advance. Recording a duration increments the first bucket whose upper
+/// edge is `>=` it; percentiles walk the cumulative counts until the target
+/// fraction is reached.
+struct LatencyHistogram {
+    edges: Vec<f64>,
+    counts: Vec<u64>,
+}
+
+impl LatencyHistogram {
+    const NUM_BUCKETS: usize = 128;
+    const MIN_SECONDS: f64 = 1e-6;
+    const MAX_SECONDS: f64 = 10.0;
+
+    fn new() -> Self {
+        let n = Self::NUM_BUCKETS;
+        let ratio = Self::MAX_SECONDS / Self::MIN_SECONDS;
+        let edges = (0..n)
+            .map(|i| Self::MIN_SECONDS * ratio.powf(i as f64 / (n - 1) as f64))
+            .collect();
+        LatencyHistogram { edges, counts: vec![0; n] }
+    }
+
+    fn record(&mut self, seconds: f64) {
+        let bucket = match self.edges.binary_search_by(|edge| edge.partial_cmp(&seconds).unwrap()) {
+            Ok(idx) => idx,
+            Err(idx) => idx,
+        };
+        let bucket = bucket.min(self.counts.len() - 1);
+        self.counts[bucket] += 1;
+    }
+
+    /// Walks cumulative bucket counts until the cumulative fraction reaches
+    /// `p` (e.g. `0.99` for p99), returning that bucket's upper edge in
+    /// milliseconds.
+    fn percentile_ms(&self, p: f64) -> f64 {
+        let total: u64 = self.counts.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let target = (p * total as f64).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (i, &count) in self.counts.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                return self.edges[i] * 1000.0;
+            }
+        }
+        self.edges[self.edges.len() - 1] * 1000.0
+    }
+}
 
 fn create_matrix(rows: usize, cols: usize) -> Vec<Vec<f64>> {
     let mut rng = thread_rng();
@@ -12,13 +88,15 @@ fn create_matrix(rows: usize, cols: usize) -> Vec<Vec<f64>> {
         .collect()
 }
 
-fn multiply_matrices(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+/// Textbook i-j-k triple loop. The hot inner loop reads `b[k][j]`, which
+/// strides across `b`'s rows and thrashes cache for larger matrices.
+fn multiply_naive(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
     let rows_a = a.len();
     let cols_a = a[0].len();
     let cols_b = b[0].len();
-    
+
     let mut result = vec![vec![0.0; cols_b]; rows_a];
-    
+
     for i in 0..rows_a {
         for j in 0..cols_b {
             for k in 0..cols_a {
@@ -26,36 +104,184 @@ fn multiply_matrices(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
             }
         }
     }
-    
+
+    result
+}
+
+fn transpose(m: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let rows = m.len();
+    let cols = m[0].len();
+    let mut t = vec![vec![0.0; rows]; cols];
+    for (i, row) in m.iter().enumerate() {
+        for (j, &value) in row.iter().enumerate() {
+            t[j][i] = value;
+        }
+    }
+    t
+}
+
+/// Pre-transposes `b` so the hot inner loop reads `a[i][k]` and `b_t[j][k]`,
+/// two contiguous rows, instead of striding across `b`'s rows.
+fn multiply_transposed(a: &[Vec<f64>], b: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let rows_a = a.len();
+    let cols_a = a[0].len();
+    let cols_b = b[0].len();
+    let b_t = transpose(b);
+
+    let mut result = vec![vec![0.0; cols_b]; rows_a];
+
+    for i in 0..rows_a {
+        for j in 0..cols_b {
+            let mut sum = 0.0;
+            for k in 0..cols_a {
+                sum += a[i][k] * b_t[j][k];
+            }
+            result[i][j] = sum;
+        }
+    }
+
+    result
+}
+
+/// Cache-blocked/tiled variant: iterates over `tile_size x tile_size` blocks
+/// of the `ii`/`jj`/`kk` index space so each block's working set fits in
+/// cache, then does the block's sub-products with the same i-j-k order.
+fn multiply_blocked(a: &[Vec<f64>], b: &[Vec<f64>], tile_size: usize) -> Vec<Vec<f64>> {
+    let rows_a = a.len();
+    let cols_a = a[0].len();
+    let cols_b = b[0].len();
+
+    let mut result = vec![vec![0.0; cols_b]; rows_a];
+
+    let mut ii = 0;
+    while ii < rows_a {
+        let i_end = (ii + tile_size).min(rows_a);
+        let mut jj = 0;
+        while jj < cols_b {
+            let j_end = (jj + tile_size).min(cols_b);
+            let mut kk = 0;
+            while kk < cols_a {
+                let k_end = (kk + tile_size).min(cols_a);
+                for i in ii..i_end {
+                    for j in jj..j_end {
+                        let mut sum = result[i][j];
+                        for k in kk..k_end {
+                            sum += a[i][k] * b[k][j];
+                        }
+                        result[i][j] = sum;
+                    }
+                }
+                kk = k_end;
+            }
+            jj = j_end;
+        }
+        ii = i_end;
+    }
+
     result
 }
 
+fn multiply_with(algorithm: &str, a: &[Vec<f64>], b: &[Vec<f64>], tile_size: usize) -> Vec<Vec<f64>> {
+    match algorithm {
+        "transposed" => multiply_transposed(a, b),
+        "blocked" => multiply_blocked(a, b, tile_size),
+        _ => multiply_naive(a, b),
+    }
+}
+
+/// Sanity-checks that every selected algorithm produces identical results on
+/// a small fixed 3x3 matrix before running the timed benchmark.
+fn verify_algorithms(algorithms: &[String], tile_size: usize) {
+    let a = vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 9.0],
+    ];
+    let b = vec![
+        vec![9.0, 8.0, 7.0],
+        vec![6.0, 5.0, 4.0],
+        vec![3.0, 2.0, 1.0],
+    ];
+    let expected = multiply_naive(&a, &b);
+
+    for algorithm in algorithms {
+        let actual = multiply_with(algorithm, &a, &b, tile_size);
+        if actual != expected {
+            eprintln!(
+                "Warning: algorithm '{}' disagrees with the naive result on the fixed 3x3 check",
+                algorithm
+            );
+        }
+    }
+}
+
+fn gflops(size: usize, seconds: f64) -> f64 {
+    if seconds > 0.0 {
+        (2.0 * (size as f64).powi(3)) / seconds / 1e9
+    } else {
+        0.0
+    }
+}
+
 fn main() {
-    let size = 200;  // Matrix size (200x200)
-    
+    let args: Vec<String> = env::args().collect();
+
+    let (size, tile_size, algorithms) = if args.len() > 1 {
+        let config_file = &args[1];
+        let config_content = fs::read_to_string(config_file).unwrap_or_else(|e| {
+            eprintln!("Error: Config file '{}' not found: {}", config_file, e);
+            std::process::exit(1);
+        });
+        let config: Config = serde_json::from_str(&config_content).unwrap_or_else(|e| {
+            eprintln!("Error: Invalid JSON in config file: {}", e);
+            std::process::exit(1);
+        });
+        (
+            config.parameters.size.unwrap_or(200),
+            config.parameters.tile_size.unwrap_or(32),
+            config
+                .parameters
+                .algorithms
+                .unwrap_or_else(|| vec!["naive".to_string(), "transposed".to_string(), "blocked".to_string()]),
+        )
+    } else {
+        (200, 32, vec!["naive".to_string(), "transposed".to_string(), "blocked".to_string()])
+    };
+
+    verify_algorithms(&algorithms, tile_size);
+
     println!("Multiplying two {}x{} matrices...", size, size);
-    
+
     // Create matrices
     let create_start = Instant::now();
     let matrix_a = create_matrix(size, size);
     let matrix_b = create_matrix(size, size);
     let create_time = create_start.elapsed();
-    
-    // Multiply matrices
-    let multiply_start = Instant::now();
-    let result = multiply_matrices(&matrix_a, &matrix_b);
-    let multiply_time = multiply_start.elapsed();
-    
-    let total_time = create_time + multiply_time;
-    
-    // Verify result dimensions
-    let result_rows = result.len();
-    let result_cols = result[0].len();
-    
-    println!("Result: {}x{} matrix", result_rows, result_cols);
-    println!("Sample result[0][0]: {:.6}", result[0][0]);
     println!("Timing:");
     println!("  Matrix creation: {:.6} seconds", create_time.as_secs_f64());
-    println!("  Matrix multiplication: {:.6} seconds", multiply_time.as_secs_f64());
-    println!("  Total time: {:.6} seconds", total_time.as_secs_f64());
+
+    // Multiply matrices with each selected algorithm, repeating a few trials
+    // per algorithm to get a meaningful latency distribution.
+    let num_trials = 5;
+    for algorithm in &algorithms {
+        let mut histogram = LatencyHistogram::new();
+        let mut result = Vec::new();
+        for _ in 0..num_trials {
+            let trial_start = Instant::now();
+            result = multiply_with(algorithm, &matrix_a, &matrix_b, tile_size);
+            histogram.record(trial_start.elapsed().as_secs_f64());
+        }
+        let multiply_time_ms = histogram.percentile_ms(0.50);
+        let avg_time_seconds = multiply_time_ms / 1000.0;
+
+        println!("Algorithm: {}", algorithm);
+        println!("  Result: {}x{} matrix, sample result[0][0]: {:.6}", result.len(), result[0].len(), result[0][0]);
+        println!("  Multiply time (median of {}): {:.6} seconds", num_trials, avg_time_seconds);
+        println!("  GFLOP/s: {:.3}", gflops(size, avg_time_seconds));
+        println!("  Latency percentiles (ms): p50={:.3} p90={:.3} p99={:.3} p999={:.3}",
+            histogram.percentile_ms(0.50),
+            histogram.percentile_ms(0.90),
+            histogram.percentile_ms(0.99),
+            histogram.percentile_ms(0.999));
+    }
 }
\ No newline at end of file
@@ -1,161 +1,824 @@
 use std::env;
 use std::fs;
 use std::time::{Duration, Instant};
-use std::net::{ToSocketAddrs, TcpStream};
+use std::net::UdpSocket;
 use std::thread;
 use std::sync::{Arc, Mutex};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use rand::{thread_rng, Rng};
 use serde_json::{json, Value};
 
+/// DNS query types this client knows how to encode/decode, mapped to their
+/// wire-format QTYPE codes (RFC 1035 section 3.2.2/3.2.3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueryType {
+    A,
+    Aaaa,
+    Mx,
+    Ns,
+    Cname,
+    Txt,
+}
+
+impl QueryType {
+    fn parse(name: &str) -> QueryType {
+        match name.to_uppercase().as_str() {
+            "AAAA" => QueryType::Aaaa,
+            "MX" => QueryType::Mx,
+            "NS" => QueryType::Ns,
+            "CNAME" => QueryType::Cname,
+            "TXT" => QueryType::Txt,
+            _ => QueryType::A,
+        }
+    }
+
+    fn code(&self) -> u16 {
+        match self {
+            QueryType::A => 1,
+            QueryType::Ns => 2,
+            QueryType::Cname => 5,
+            QueryType::Mx => 15,
+            QueryType::Txt => 16,
+            QueryType::Aaaa => 28,
+        }
+    }
+}
+
+/// Encodes `domain` as length-prefixed labels terminated by a zero byte,
+/// e.g. `"www.example.com"` -> `[3]www[7]example[3]com[0]`.
+fn encode_domain_name(domain: &str) -> Vec<u8> {
+    let mut out = Vec::new();
+    for label in domain.trim_end_matches('.').split('.') {
+        out.push(label.len() as u8);
+        out.extend_from_slice(label.as_bytes());
+    }
+    out.push(0);
+    out
+}
+
+/// Builds a 12-byte DNS header plus a single question, per RFC 1035 section 4.
+fn build_dns_query(id: u16, domain: &str, qtype: QueryType) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(32);
+
+    packet.extend_from_slice(&id.to_be_bytes());
+    packet.extend_from_slice(&0x0100u16.to_be_bytes()); // flags: RD=1
+    packet.extend_from_slice(&1u16.to_be_bytes()); // QDCOUNT
+    packet.extend_from_slice(&0u16.to_be_bytes()); // ANCOUNT
+    packet.extend_from_slice(&0u16.to_be_bytes()); // NSCOUNT
+    packet.extend_from_slice(&0u16.to_be_bytes()); // ARCOUNT
+
+    packet.extend_from_slice(&encode_domain_name(domain));
+    packet.extend_from_slice(&qtype.code().to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes()); // QCLASS = IN
+
+    packet
+}
+
+/// Reads a (possibly compressed) domain name starting at `offset` within the
+/// whole `packet`. A label byte with its top two bits set (`0xC0`) is a
+/// compression pointer: the low 14 bits give the offset to jump to. Returns
+/// the decoded name and the offset just past it in the *original* stream
+/// (i.e. not following any pointer jump), since RDLENGTH accounting needs
+/// the pre-jump length.
+fn decode_name(packet: &[u8], offset: usize) -> Result<(String, usize), String> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut pos = offset;
+    let mut end_pos: Option<usize> = None;
+    let mut jumps = 0;
+
+    loop {
+        if pos >= packet.len() {
+            return Err("truncated packet while decoding name".to_string());
+        }
+        let len = packet[pos];
+
+        if len == 0 {
+            pos += 1;
+            if end_pos.is_none() {
+                end_pos = Some(pos);
+            }
+            break;
+        } else if len & 0xC0 == 0xC0 {
+            if pos + 1 >= packet.len() {
+                return Err("truncated compression pointer".to_string());
+            }
+            let pointer = (((len & 0x3F) as usize) << 8) | packet[pos + 1] as usize;
+            if end_pos.is_none() {
+                end_pos = Some(pos + 2);
+            }
+            jumps += 1;
+            if jumps > 32 {
+                return Err("too many compression pointer jumps".to_string());
+            }
+            pos = pointer;
+        } else {
+            let start = pos + 1;
+            let stop = start + len as usize;
+            if stop > packet.len() {
+                return Err("truncated label".to_string());
+            }
+            labels.push(String::from_utf8_lossy(&packet[start..stop]).to_string());
+            pos = stop;
+        }
+    }
+
+    Ok((labels.join("."), end_pos.unwrap_or(pos)))
+}
+
+#[derive(Debug, Clone)]
+struct DnsRecord {
+    name: String,
+    rtype: u16,
+    ttl: u32,
+    data: String,
+}
+
+/// Distinguishes the two flavors of "no records" DNS answer, per RFC 2308:
+/// the domain itself doesn't exist (NXDOMAIN) versus it exists but has no
+/// records of the queried type (NoData, i.e. RCODE NOERROR with ANCOUNT 0).
+/// Both are legitimate, authoritative answers worth negative-caching, not
+/// failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NegativeKind {
+    NxDomain,
+    NoData,
+}
+
+/// Parsed answer section of a DNS response, plus the addresses extracted
+/// from any A/AAAA records for convenience. `negative`/`soa_minimum` are set
+/// when the response is an authoritative "no records" answer rather than a
+/// list of records; `soa_minimum` comes from the authority section's SOA
+/// record and bounds how long that negative result may be cached (RFC 2308).
+struct ParsedResponse {
+    addresses: Vec<String>,
+    records: Vec<DnsRecord>,
+    negative: Option<NegativeKind>,
+    soa_minimum: Option<u32>,
+}
+
+/// Classifies why a domain/query-type lookup didn't produce an answer,
+/// inspired by the status buckets resolver-health trackers use to tell a
+/// slow-but-answering nameserver apart from a dead one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DnsStatus {
+    Good,
+    Timeout,
+    TimeoutDuringRequest,
+    ProtocolViolation,
+    Refused,
+    ServFail,
+    NxDomain,
+    NoData,
+    Error,
+}
+
+impl DnsStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            DnsStatus::Good => "good",
+            DnsStatus::Timeout => "timeout",
+            DnsStatus::TimeoutDuringRequest => "timeout_during_request",
+            DnsStatus::ProtocolViolation => "protocol_violation",
+            DnsStatus::Refused => "refused",
+            DnsStatus::ServFail => "servfail",
+            DnsStatus::NxDomain => "nxdomain",
+            DnsStatus::NoData => "nodata",
+            DnsStatus::Error => "error",
+        }
+    }
+}
+
+/// A single nameserver/query-type attempt's outcome, carrying enough detail
+/// for `resolve_domain_with_timeout` to derive an overall `DnsStatus` across
+/// every query type it tried for a domain.
+#[derive(Debug)]
+enum ResolveError {
+    Timeout,
+    ProtocolViolation(String),
+    Refused,
+    ServFail,
+    Other(String),
+}
+
+impl std::fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolveError::Timeout => write!(f, "query timed out"),
+            ResolveError::ProtocolViolation(msg) => write!(f, "protocol violation: {}", msg),
+            ResolveError::Refused => write!(f, "query refused"),
+            ResolveError::ServFail => write!(f, "server failure"),
+            ResolveError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+fn parse_dns_response(packet: &[u8], expected_id: u16) -> Result<ParsedResponse, ResolveError> {
+    if packet.len() < 12 {
+        return Err(ResolveError::ProtocolViolation("response shorter than DNS header".to_string()));
+    }
+
+    let id = u16::from_be_bytes([packet[0], packet[1]]);
+    if id != expected_id {
+        return Err(ResolveError::ProtocolViolation(format!("response ID {} does not match query ID {}", id, expected_id)));
+    }
+
+    let flags = u16::from_be_bytes([packet[2], packet[3]]);
+    let rcode = flags & 0x000F;
+    let qdcount = u16::from_be_bytes([packet[4], packet[5]]);
+    let ancount = u16::from_be_bytes([packet[6], packet[7]]);
+    let nscount = u16::from_be_bytes([packet[8], packet[9]]);
+
+    let negative = match rcode {
+        0 => None,
+        3 => Some(NegativeKind::NxDomain),
+        2 => return Err(ResolveError::ServFail),
+        5 => return Err(ResolveError::Refused),
+        other => return Err(ResolveError::ProtocolViolation(format!("unexpected RCODE {}", other))),
+    };
+
+    let mut pos = 12;
+
+    // Skip the echoed question section.
+    for _ in 0..qdcount {
+        let (_, next) = decode_name(packet, pos).map_err(ResolveError::ProtocolViolation)?;
+        pos = next + 4; // QTYPE + QCLASS
+    }
+
+    let mut addresses = Vec::new();
+    let mut records = Vec::new();
+
+    for _ in 0..ancount {
+        let (name, next) = decode_name(packet, pos).map_err(ResolveError::ProtocolViolation)?;
+        pos = next;
+
+        if pos + 10 > packet.len() {
+            return Err(ResolveError::ProtocolViolation("truncated resource record header".to_string()));
+        }
+        let rtype = u16::from_be_bytes([packet[pos], packet[pos + 1]]);
+        let ttl = u32::from_be_bytes([packet[pos + 4], packet[pos + 5], packet[pos + 6], packet[pos + 7]]);
+        let rdlength = u16::from_be_bytes([packet[pos + 8], packet[pos + 9]]) as usize;
+        pos += 10;
+
+        if pos + rdlength > packet.len() {
+            return Err(ResolveError::ProtocolViolation("truncated resource record data".to_string()));
+        }
+        let rdata = &packet[pos..pos + rdlength];
+
+        let data = match rtype {
+            1 if rdlength == 4 => {
+                let ip = format!("{}.{}.{}.{}", rdata[0], rdata[1], rdata[2], rdata[3]);
+                addresses.push(ip.clone());
+                ip
+            }
+            28 if rdlength == 16 => {
+                let segments: Vec<String> = rdata.chunks(2).map(|c| format!("{:02x}{:02x}", c[0], c[1])).collect();
+                let ip = segments.join(":");
+                addresses.push(ip.clone());
+                ip
+            }
+            2 | 5 => decode_name(packet, pos).map(|(n, _)| n).unwrap_or_default(),
+            15 if rdlength >= 2 => {
+                let preference = u16::from_be_bytes([rdata[0], rdata[1]]);
+                let exchange = decode_name(packet, pos + 2).map(|(n, _)| n).unwrap_or_default();
+                format!("{} {}", preference, exchange)
+            }
+            16 => {
+                let mut text = String::new();
+                let mut i = 0;
+                while i < rdata.len() {
+                    let len = rdata[i] as usize;
+                    let start = i + 1;
+                    let stop = (start + len).min(rdata.len());
+                    text.push_str(&String::from_utf8_lossy(&rdata[start..stop]));
+                    i = stop;
+                }
+                text
+            }
+            _ => format!("{} bytes of unrecognized RDATA", rdlength),
+        };
+
+        records.push(DnsRecord { name, rtype, ttl, data });
+        pos += rdlength;
+    }
+
+    // A NOERROR response with no answers is NoData rather than success; an
+    // authoritative nameserver still signals this with RCODE 0.
+    let negative = negative.or(if records.is_empty() { Some(NegativeKind::NoData) } else { None });
+
+    // For negative responses, the authority section carries the zone's SOA
+    // record, whose MINIMUM field (the last 4 bytes of its RDATA, RFC 1035
+    // section 3.3.13) bounds how long the negative answer may be cached.
+    let mut soa_minimum = None;
+    if negative.is_some() {
+        for _ in 0..nscount {
+            let (_, next) = match decode_name(packet, pos) {
+                Ok(v) => v,
+                Err(_) => break,
+            };
+            pos = next;
+            if pos + 10 > packet.len() {
+                break;
+            }
+            let rtype = u16::from_be_bytes([packet[pos], packet[pos + 1]]);
+            let rdlength = u16::from_be_bytes([packet[pos + 8], packet[pos + 9]]) as usize;
+            pos += 10;
+            if pos + rdlength > packet.len() {
+                break;
+            }
+            if rtype == 6 {
+                // SOA RDATA: MNAME, RNAME, then 5 u32 fields; MINIMUM is the last one.
+                if let Ok((_, after_mname)) = decode_name(packet, pos) {
+                    if let Ok((_, after_rname)) = decode_name(packet, after_mname) {
+                        if after_rname + 20 <= packet.len() {
+                            soa_minimum = Some(u32::from_be_bytes([
+                                packet[after_rname + 16],
+                                packet[after_rname + 17],
+                                packet[after_rname + 18],
+                                packet[after_rname + 19],
+                            ]));
+                        }
+                    }
+                }
+            }
+            pos += rdlength;
+        }
+    }
+
+    Ok(ParsedResponse { addresses, records, negative, soa_minimum })
+}
+
 #[derive(Debug, Clone)]
 struct DnsResult {
     domain: String,
-    success: bool,
+    status: DnsStatus,
     response_time_ms: f64,
     ip_addresses: Vec<String>,
     error: Option<String>,
+    from_cache: bool,
 }
 
 impl DnsResult {
     fn new(domain: String) -> Self {
         DnsResult {
             domain,
-            success: false,
+            status: DnsStatus::Error,
             response_time_ms: 0.0,
             ip_addresses: Vec::new(),
             error: None,
+            from_cache: false,
         }
     }
-    
+
+    fn is_success(&self) -> bool {
+        self.status == DnsStatus::Good
+    }
+
     fn to_json(&self) -> Value {
         json!({
             "domain": self.domain,
-            "success": self.success,
+            "success": self.is_success(),
+            "status": self.status.as_str(),
             "response_time_ms": self.response_time_ms,
             "ip_addresses": self.ip_addresses,
-            "error": self.error
+            "error": self.error,
+            "from_cache": self.from_cache
         })
     }
 }
 
-// Simple DNS cache
+/// A cached resolution plus the instant it stops being valid. Positive
+/// answers expire after the minimum TTL seen across their records; negative
+/// answers (NXDOMAIN/NoData) expire after the zone's SOA `minimum` field, or
+/// `negative_ttl` when no SOA was available (RFC 2308).
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    result: DnsResult,
+    expires_at: Instant,
+}
+
+// Simple TTL-aware DNS cache. Only answered lookups (positive or negative)
+// are ever inserted; transient failures (timeouts, SERVFAIL, etc.) are never
+// cached so one bad attempt can't poison every later iteration.
 lazy_static::lazy_static! {
-    static ref DNS_CACHE: Arc<Mutex<HashMap<String, DnsResult>>> = 
+    static ref DNS_CACHE: Arc<Mutex<HashMap<String, CacheEntry>>> =
         Arc::new(Mutex::new(HashMap::new()));
 }
 
-fn resolve_domain_with_timeout(domain: &str, timeout_secs: u64) -> DnsResult {
-    // Check cache first
+/// Queries a single nameserver over UDP for a single `domain`/`qtype` pair,
+/// measuring the actual wire round-trip rather than delegating to the OS
+/// resolver. Tries each nameserver in `nameservers` in order until one
+/// answers or the list is exhausted.
+fn query_nameservers(domain: &str, qtype: QueryType, nameservers: &[String], timeout_secs: u64) -> Result<(ParsedResponse, f64), ResolveError> {
+    let mut last_error = ResolveError::Other("no nameservers configured".to_string());
+
+    for nameserver in nameservers {
+        let server_addr = format!("{}:53", nameserver);
+        let id = thread_rng().gen::<u16>();
+        let query = build_dns_query(id, domain, qtype);
+
+        let socket = match UdpSocket::bind("0.0.0.0:0") {
+            Ok(s) => s,
+            Err(e) => {
+                last_error = ResolveError::Other(format!("failed to bind UDP socket: {}", e));
+                continue;
+            }
+        };
+        // Honor the caller's per-query timeout on the socket itself, so a
+        // nameserver that never answers can't block this attempt (and, in
+        // turn, the whole benchmark) indefinitely.
+        if socket.set_read_timeout(Some(Duration::from_secs(timeout_secs))).is_err() {
+            last_error = ResolveError::Other("failed to set socket read timeout".to_string());
+            continue;
+        }
+
+        let start = Instant::now();
+        if let Err(e) = socket.send_to(&query, &server_addr) {
+            last_error = ResolveError::Other(format!("failed to send query to {}: {}", nameserver, e));
+            continue;
+        }
+
+        let mut buf = [0u8; 512];
+        match socket.recv_from(&mut buf) {
+            Ok((len, _)) => {
+                let rtt_ms = start.elapsed().as_secs_f64() * 1000.0;
+                match parse_dns_response(&buf[..len], id) {
+                    Ok(parsed) => return Ok((parsed, rtt_ms)),
+                    Err(e) => {
+                        last_error = e;
+                        continue;
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock || e.kind() == std::io::ErrorKind::TimedOut => {
+                last_error = ResolveError::Timeout;
+                continue;
+            }
+            Err(e) => {
+                last_error = ResolveError::Other(format!("no response from {}: {}", nameserver, e));
+                continue;
+            }
+        }
+    }
+
+    Err(last_error)
+}
+
+fn resolve_domain_with_timeout(domain: &str, timeout_secs: u64, nameservers: &[String], query_types: &[QueryType], negative_ttl: u64) -> DnsResult {
+    // Check cache first, discarding (and letting the later insert overwrite)
+    // any entry that has already expired.
     {
         let cache = DNS_CACHE.lock().unwrap();
-        if let Some(cached_result) = cache.get(domain) {
-            return cached_result.clone();
+        if let Some(entry) = cache.get(domain) {
+            if Instant::now() < entry.expires_at {
+                let mut cached = entry.result.clone();
+                cached.from_cache = true;
+                return cached;
+            }
         }
     }
-    
+
     let mut result = DnsResult::new(domain.to_string());
-    let start = Instant::now();
-    
-    // Create address string for resolution
-    let address = format!("{}:53", domain);
-    
-    // Use a separate thread for timeout control
     let domain_clone = domain.to_string();
+    let nameservers = nameservers.to_vec();
+    let query_types = query_types.to_vec();
+    let query_type_count = query_types.len();
+
+    // Run the UDP round-trip on a worker thread and join it with a wall-clock
+    // deadline, so a nameserver that never replies at all (rather than
+    // merely replying slowly) can't hang the whole benchmark.
     let handle = thread::spawn(move || {
-        let address_with_port = format!("{}:80", domain_clone);
-        match address_with_port.to_socket_addrs() {
-            Ok(addrs) => {
-                let ip_addresses: Vec<String> = addrs
-                    .map(|addr| addr.ip().to_string())
-                    .collect();
-                Ok(ip_addresses)
+        let mut addresses = Vec::new();
+        let mut rtt_ms: f64 = 0.0;
+        let mut errors: Vec<ResolveError> = Vec::new();
+        let mut min_ttl: Option<u32> = None;
+        let mut negative_kind: Option<NegativeKind> = None;
+        let mut soa_minimum: Option<u32> = None;
+
+        for qtype in &query_types {
+            match query_nameservers(&domain_clone, *qtype, &nameservers, timeout_secs) {
+                Ok((parsed, rtt)) => {
+                    rtt_ms = rtt_ms.max(rtt);
+                    addresses.extend(parsed.addresses);
+                    for record in &parsed.records {
+                        min_ttl = Some(min_ttl.map_or(record.ttl, |m| m.min(record.ttl)));
+                    }
+                    if let Some(kind) = parsed.negative {
+                        negative_kind = Some(kind);
+                        soa_minimum = soa_minimum.or(parsed.soa_minimum);
+                    }
+                }
+                Err(e) => errors.push(e),
             }
-            Err(e) => Err(format!("DNS resolution failed: {}", e)),
         }
+
+        (addresses, rtt_ms, errors, min_ttl, negative_kind, soa_minimum)
     });
-    
-    match handle.join() {
-        Ok(Ok(ip_addresses)) => {
-            result.success = !ip_addresses.is_empty();
-            result.ip_addresses = ip_addresses;
-            result.response_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let deadline = Duration::from_secs(timeout_secs * query_type_count.max(1) as u64 + 1);
+    let start = Instant::now();
+    loop {
+        if handle.is_finished() {
+            break;
         }
-        Ok(Err(e)) => {
-            result.error = Some(e);
+        if start.elapsed() > deadline {
+            result.status = DnsStatus::Timeout;
+            result.error = Some("DNS resolution exceeded overall timeout".to_string());
             result.response_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+            return result;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    // TTL the cache entry will carry: the minimum record TTL for a positive
+    // answer, the SOA minimum (or `negative_ttl`) for a negative one. Real
+    // failures (timeouts, SERVFAIL, ...) are never cached at all.
+    let mut cache_ttl_secs: Option<u64> = None;
+
+    match handle.join() {
+        Ok((ip_addresses, rtt_ms, errors, min_ttl, negative_kind, soa_minimum)) => {
+            result.ip_addresses = ip_addresses;
+            result.response_time_ms = rtt_ms;
+
+            if !result.ip_addresses.is_empty() || min_ttl.is_some() {
+                // Some query type answered; a sibling query type timing out
+                // is reported as a partial/intermittent timeout rather than
+                // a clean success, since it signals flaky resolver behavior.
+                result.status = if errors.iter().any(|e| matches!(e, ResolveError::Timeout)) {
+                    DnsStatus::TimeoutDuringRequest
+                } else {
+                    DnsStatus::Good
+                };
+                cache_ttl_secs = Some(min_ttl.unwrap_or(60) as u64);
+            } else if let Some(kind) = negative_kind {
+                result.status = match kind {
+                    NegativeKind::NxDomain => DnsStatus::NxDomain,
+                    NegativeKind::NoData => DnsStatus::NoData,
+                };
+                cache_ttl_secs = Some(soa_minimum.map(|m| m as u64).unwrap_or(negative_ttl));
+            } else if !errors.is_empty() && errors.iter().all(|e| matches!(e, ResolveError::Timeout)) {
+                result.status = DnsStatus::Timeout;
+                result.error = Some("all query types timed out".to_string());
+            } else if errors.iter().any(|e| matches!(e, ResolveError::ServFail)) {
+                result.status = DnsStatus::ServFail;
+                result.error = Some("server failure".to_string());
+            } else if errors.iter().any(|e| matches!(e, ResolveError::Refused)) {
+                result.status = DnsStatus::Refused;
+                result.error = Some("query refused".to_string());
+            } else if errors.iter().any(|e| matches!(e, ResolveError::ProtocolViolation(_))) {
+                result.status = DnsStatus::ProtocolViolation;
+                result.error = errors.iter().find_map(|e| match e {
+                    ResolveError::ProtocolViolation(msg) => Some(msg.clone()),
+                    _ => None,
+                });
+            } else {
+                result.status = DnsStatus::Error;
+                result.error = errors.first().map(|e| e.to_string());
+            }
         }
         Err(_) => {
+            result.status = DnsStatus::Error;
             result.error = Some("Thread panicked during DNS resolution".to_string());
             result.response_time_ms = start.elapsed().as_secs_f64() * 1000.0;
         }
     }
-    
-    // Cache the result
-    {
+
+    // Cache the result, but only if it's an actual answer (positive or
+    // negative); transient failures are left uncached so a single bad
+    // attempt can't poison every later iteration.
+    if let Some(ttl_secs) = cache_ttl_secs {
         let mut cache = DNS_CACHE.lock().unwrap();
-        cache.insert(domain.to_string(), result.clone());
+        cache.insert(domain.to_string(), CacheEntry {
+            result: result.clone(),
+            expires_at: Instant::now() + Duration::from_secs(ttl_secs),
+        });
     }
-    
+
     result
 }
 
-fn resolve_domain(domain: &str, timeout_secs: u64) -> DnsResult {
-    resolve_domain_with_timeout(domain, timeout_secs)
+fn resolve_domain(domain: &str, timeout_secs: u64, nameservers: &[String], query_types: &[QueryType], negative_ttl: u64) -> DnsResult {
+    resolve_domain_with_timeout(domain, timeout_secs, nameservers, query_types, negative_ttl)
 }
 
-fn resolve_domains_sequential(domains: &[String], timeout_secs: u64) -> Vec<DnsResult> {
+fn resolve_domains_sequential(domains: &[String], timeout_secs: u64, nameservers: &[String], query_types: &[QueryType], negative_ttl: u64) -> Vec<DnsResult> {
     let mut results = Vec::new();
-    
+
     for domain in domains {
-        let result = resolve_domain(domain, timeout_secs);
-        eprintln!("  Resolved {}: {} ({:.2}ms)", 
-                  domain, 
-                  if result.success { "✓" } else { "✗" }, 
-                  result.response_time_ms);
+        let result = resolve_domain(domain, timeout_secs, nameservers, query_types, negative_ttl);
+        eprintln!("  Resolved {}: {} ({:.2}ms{})",
+                  domain,
+                  if result.is_success() { "✓" } else { "✗" },
+                  result.response_time_ms,
+                  if result.from_cache { ", cached" } else { "" });
         results.push(result);
     }
-    
+
     results
 }
 
-fn resolve_domains_concurrent(domains: &[String], max_workers: usize, timeout_secs: u64) -> Vec<DnsResult> {
+fn resolve_domains_concurrent(domains: &[String], max_workers: usize, timeout_secs: u64, nameservers: &[String], query_types: &[QueryType], negative_ttl: u64) -> Vec<DnsResult> {
     let results = Arc::new(Mutex::new(Vec::new()));
     let mut handles = Vec::new();
-    
+
     // Split domains into chunks for workers
     let chunk_size = (domains.len() + max_workers - 1) / max_workers;
-    
+
     for chunk in domains.chunks(chunk_size) {
         let chunk_domains = chunk.to_vec();
         let results_clone = Arc::clone(&results);
-        
+        let nameservers = nameservers.to_vec();
+        let query_types = query_types.to_vec();
+
         let handle = thread::spawn(move || {
             for domain in chunk_domains {
-                let result = resolve_domain(&domain, timeout_secs);
-                eprintln!("  Resolved {}: {} ({:.2}ms)", 
-                          domain, 
-                          if result.success { "✓" } else { "✗" }, 
-                          result.response_time_ms);
-                
+                let result = resolve_domain(&domain, timeout_secs, &nameservers, &query_types, negative_ttl);
+                eprintln!("  Resolved {}: {} ({:.2}ms{})",
+                          domain,
+                          if result.is_success() { "✓" } else { "✗" },
+                          result.response_time_ms,
+                          if result.from_cache { ", cached" } else { "" });
+
                 let mut results_guard = results_clone.lock().unwrap();
                 results_guard.push(result);
             }
         });
-        
+
         handles.push(handle);
     }
-    
+
     // Wait for all threads to complete
     for handle in handles {
         handle.join().unwrap();
     }
-    
+
     let mut final_results = results.lock().unwrap().clone();
     final_results.sort_by(|a, b| a.domain.cmp(&b.domain));
-    
+
     final_results
 }
 
+/// Paces dispatch to at most `rate_per_sec` acquisitions per second,
+/// regardless of how many workers are contending for tokens, using a classic
+/// token bucket: tokens accrue continuously at `rate_per_sec` up to
+/// `capacity`, and `acquire` blocks until one is available.
+struct TokenBucket {
+    rate_per_sec: f64,
+    capacity: f64,
+    state: Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    fn new(rate_per_sec: f64) -> Self {
+        let rate_per_sec = rate_per_sec.max(0.001);
+        TokenBucket {
+            rate_per_sec,
+            capacity: rate_per_sec.max(1.0),
+            state: Mutex::new((rate_per_sec.max(1.0), Instant::now())),
+        }
+    }
+
+    /// Blocks until a token is available, consumes it, and returns how long
+    /// the caller had to wait for it (the throttling-induced delay).
+    fn acquire(&self) -> Duration {
+        let start = Instant::now();
+        loop {
+            let got_token = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+                let elapsed = now.duration_since(state.1).as_secs_f64();
+                state.0 = (state.0 + elapsed * self.rate_per_sec).min(self.capacity);
+                state.1 = now;
+                if state.0 >= 1.0 {
+                    state.0 -= 1.0;
+                    true
+                } else {
+                    false
+                }
+            };
+            if got_token {
+                return start.elapsed();
+            }
+            thread::sleep(Duration::from_millis(2));
+        }
+    }
+}
+
+/// Rate-limited concurrent resolution: `max_workers` threads pull domains off
+/// a shared queue, but each must acquire a token from a shared `TokenBucket`
+/// before dispatching, so no more than `max_queries_per_second` queries are
+/// launched in aggregate no matter how many workers are running. Returns the
+/// resolved results alongside the total time workers spent waiting on the
+/// bucket, so callers can tell throttling-induced delay apart from actual
+/// resolver latency.
+fn resolve_domains_rate_limited(
+    domains: &[String],
+    max_workers: usize,
+    max_queries_per_second: f64,
+    timeout_secs: u64,
+    nameservers: &[String],
+    query_types: &[QueryType],
+    negative_ttl: u64,
+) -> (Vec<DnsResult>, f64) {
+    let queue = Arc::new(Mutex::new(VecDeque::from(domains.to_vec())));
+    let bucket = Arc::new(TokenBucket::new(max_queries_per_second));
+    let results = Arc::new(Mutex::new(Vec::new()));
+    let total_wait_ms = Arc::new(Mutex::new(0.0f64));
+    let mut handles = Vec::new();
+
+    for _ in 0..max_workers.max(1) {
+        let queue = Arc::clone(&queue);
+        let bucket = Arc::clone(&bucket);
+        let results_clone = Arc::clone(&results);
+        let total_wait_ms = Arc::clone(&total_wait_ms);
+        let nameservers = nameservers.to_vec();
+        let query_types = query_types.to_vec();
+
+        let handle = thread::spawn(move || loop {
+            let domain = match queue.lock().unwrap().pop_front() {
+                Some(d) => d,
+                None => break,
+            };
+
+            let wait = bucket.acquire();
+            *total_wait_ms.lock().unwrap() += wait.as_secs_f64() * 1000.0;
+
+            let result = resolve_domain(&domain, timeout_secs, &nameservers, &query_types, negative_ttl);
+            eprintln!("  Resolved {}: {} ({:.2}ms{})",
+                      domain,
+                      if result.is_success() { "✓" } else { "✗" },
+                      result.response_time_ms,
+                      if result.from_cache { ", cached" } else { "" });
+
+            results_clone.lock().unwrap().push(result);
+        });
+
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut final_results = results.lock().unwrap().clone();
+    final_results.sort_by(|a, b| a.domain.cmp(&b.domain));
+    let total_wait = *total_wait_ms.lock().unwrap();
+
+    (final_results, total_wait)
+}
+
+/// Caps how many history lines `compact_history` keeps per results_db file,
+/// so an unattended long-running suite can't grow it without bound.
+const RESULTS_DB_MAX_RECORDS: usize = 500;
+/// How many trailing records the rolling baseline averages over.
+const ROLLING_BASELINE_WINDOW: usize = 10;
+
+/// Loads every previously recorded line for `mode` from the line-per-record
+/// `results_db` file at `path`. Missing file or unparseable lines are
+/// treated as "no history yet" rather than an error, since the first run
+/// against a fresh path has nothing to load.
+fn load_history(path: &str, mode: &str) -> Vec<Value> {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|record| record["mode"].as_str() == Some(mode))
+        .collect()
+}
+
+/// Appends `record` as one compact-JSON line, then compacts the file back
+/// down to `RESULTS_DB_MAX_RECORDS` lines if the append pushed it over.
+fn append_history(path: &str, record: &Value) {
+    use std::io::Write;
+    if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(path) {
+        let _ = writeln!(file, "{}", record);
+    }
+    compact_history(path);
+}
+
+fn compact_history(path: &str) {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.len() <= RESULTS_DB_MAX_RECORDS {
+        return;
+    }
+    let trimmed = lines[lines.len() - RESULTS_DB_MAX_RECORDS..].join("\n");
+    let _ = fs::write(path, trimmed + "\n");
+}
+
+fn pct_change(current: f64, previous: f64) -> Option<f64> {
+    if previous == 0.0 {
+        None
+    } else {
+        Some(((current - previous) / previous) * 100.0)
+    }
+}
+
 fn run_dns_benchmark(config: &Value) -> Value {
     let parameters = &config["parameters"];
     
@@ -180,7 +843,33 @@ fn run_dns_benchmark(config: &Value) -> Value {
     let iterations = parameters["iterations"].as_u64().unwrap_or(3) as usize;
     let timeout_secs = parameters["timeout_seconds"].as_u64().unwrap_or(5);
     let concurrent_workers = parameters["concurrent_workers"].as_u64().unwrap_or(5) as usize;
-    
+
+    let nameservers: Vec<String> = parameters["nameservers"]
+        .as_array()
+        .unwrap_or(&vec![json!("1.1.1.1"), json!("8.8.8.8")])
+        .iter()
+        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+        .collect();
+
+    let query_types: Vec<QueryType> = parameters["query_types"]
+        .as_array()
+        .unwrap_or(&vec![json!("A")])
+        .iter()
+        .filter_map(|v| v.as_str().map(QueryType::parse))
+        .collect();
+
+    // Fallback negative-cache TTL when a NXDOMAIN/NoData response carries no
+    // SOA record to bound it (RFC 2308 recommends a short, conservative value).
+    let negative_ttl = parameters["negative_ttl"].as_u64().unwrap_or(60);
+
+    // Only consulted by the "rate_limited" mode; caps aggregate dispatch rate
+    // across all workers regardless of `concurrent_workers`.
+    let max_queries_per_second = parameters["max_queries_per_second"].as_f64().unwrap_or(10.0);
+
+    // Optional path to a line-per-record history file; when set, each mode's
+    // summary is appended to it and compared against prior runs.
+    let results_db = parameters["results_db"].as_str().map(|s| s.to_string());
+
     let start_time = Instant::now();
     let mut test_cases = Vec::new();
     let mut all_resolution_times = Vec::new();
@@ -192,39 +881,49 @@ fn run_dns_benchmark(config: &Value) -> Value {
         let mut mode_resolution_times = Vec::new();
         let mut mode_successful = 0;
         let mut mode_total = 0;
+        let mut mode_status_counts: HashMap<&'static str, u32> = HashMap::new();
+        let mut mode_throttle_wait_ms = 0.0;
+        let mut mode_wall_time_ms = 0.0;
         let mut iterations_data = Vec::new();
-        
+
         for i in 0..iterations {
             eprintln!("  Iteration {}/{}...", i + 1, iterations);
-            
+
             let iteration_start = Instant::now();
-            
-            let domain_results = match mode.as_str() {
-                "sequential" => resolve_domains_sequential(&domains, timeout_secs),
-                "concurrent" => resolve_domains_concurrent(&domains, concurrent_workers, timeout_secs),
+
+            let (domain_results, throttle_wait_ms) = match mode.as_str() {
+                "sequential" => (resolve_domains_sequential(&domains, timeout_secs, &nameservers, &query_types, negative_ttl), 0.0),
+                "concurrent" => (resolve_domains_concurrent(&domains, concurrent_workers, timeout_secs, &nameservers, &query_types, negative_ttl), 0.0),
+                "rate_limited" => resolve_domains_rate_limited(&domains, concurrent_workers, max_queries_per_second, timeout_secs, &nameservers, &query_types, negative_ttl),
                 _ => {
                     eprintln!("Warning: Unknown resolution mode '{}', using sequential", mode);
-                    resolve_domains_sequential(&domains, timeout_secs)
+                    (resolve_domains_sequential(&domains, timeout_secs, &nameservers, &query_types, negative_ttl), 0.0)
                 }
             };
-            
+            mode_throttle_wait_ms += throttle_wait_ms;
+
             let iteration_total_time = iteration_start.elapsed().as_secs_f64() * 1000.0;
+            mode_wall_time_ms += iteration_total_time;
             
-            let iteration_successful = domain_results.iter().filter(|r| r.success).count();
+            let iteration_successful = domain_results.iter().filter(|r| r.is_success()).count();
             let iteration_failed = domain_results.len() - iteration_successful;
-            
+
             let iteration_avg_time: f64 = if iteration_successful > 0 {
                 domain_results.iter()
-                    .filter(|r| r.success)
+                    .filter(|r| r.is_success())
                     .map(|r| r.response_time_ms)
                     .sum::<f64>() / iteration_successful as f64
             } else {
                 0.0
             };
-            
-            // Collect timing data
+
+            // Collect timing data and tally per-status outcomes, so the
+            // summary can distinguish a slow-but-answering resolver (mostly
+            // "good", some "timeout_during_request") from a dead one (all
+            // "timeout"/"servfail").
             for result in &domain_results {
-                if result.success {
+                *mode_status_counts.entry(result.status.as_str()).or_insert(0) += 1;
+                if result.is_success() {
                     mode_resolution_times.push(result.response_time_ms);
                     all_resolution_times.push(result.response_time_ms);
                 }
@@ -241,6 +940,7 @@ fn run_dns_benchmark(config: &Value) -> Value {
                 "successful_resolutions": iteration_successful,
                 "failed_resolutions": iteration_failed,
                 "avg_resolution_time_ms": iteration_avg_time,
+                "throttle_wait_ms": throttle_wait_ms,
                 "domain_results": domain_results.iter().map(|r| r.to_json()).collect::<Vec<_>>()
             });
             
@@ -260,7 +960,57 @@ fn run_dns_benchmark(config: &Value) -> Value {
         } else {
             0.0
         };
-        
+        let achieved_qps = if mode_wall_time_ms > 0.0 {
+            mode_total as f64 / (mode_wall_time_ms / 1000.0)
+        } else {
+            0.0
+        };
+
+        // Compare this run's average against history before appending to it,
+        // so the delta reflects prior runs only, never this one.
+        let history_json = if let Some(path) = &results_db {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs_f64();
+
+            let history = load_history(path, mode);
+            let previous = history.last();
+            let baseline_start = history.len().saturating_sub(ROLLING_BASELINE_WINDOW);
+            let baseline_window = &history[baseline_start..];
+            let rolling_baseline_avg = if !baseline_window.is_empty() {
+                let sum: f64 = baseline_window.iter().filter_map(|r| r["avg_resolution_time"].as_f64()).sum();
+                Some(sum / baseline_window.len() as f64)
+            } else {
+                None
+            };
+
+            let record = json!({
+                "timestamp": timestamp,
+                "benchmark": "dns_lookup",
+                "mode": mode,
+                "avg_resolution_time": avg_resolution_time,
+                "fastest_resolution": if fastest_resolution == f64::INFINITY { 0.0 } else { fastest_resolution },
+                "slowest_resolution": slowest_resolution,
+                "success_rate": success_rate,
+                "total_successful": mode_successful,
+                "total_attempts": mode_total
+            });
+
+            let history_json = json!({
+                "previous_avg_resolution_time": previous.and_then(|r| r["avg_resolution_time"].as_f64()),
+                "pct_change_vs_previous": previous.and_then(|r| r["avg_resolution_time"].as_f64()).and_then(|p| pct_change(avg_resolution_time, p)),
+                "rolling_baseline_avg_resolution_time": rolling_baseline_avg,
+                "pct_change_vs_rolling_baseline": rolling_baseline_avg.and_then(|b| pct_change(avg_resolution_time, b)),
+                "records_in_history": history.len()
+            });
+
+            append_history(path, &record);
+            history_json
+        } else {
+            Value::Null
+        };
+
         let test_case = json!({
             "resolution_mode": mode,
             "domains_count": domains.len(),
@@ -270,9 +1020,13 @@ fn run_dns_benchmark(config: &Value) -> Value {
             "slowest_resolution": slowest_resolution,
             "success_rate": success_rate,
             "total_successful": mode_successful,
-            "total_attempts": mode_total
+            "total_attempts": mode_total,
+            "status_counts": mode_status_counts,
+            "achieved_queries_per_sec": achieved_qps,
+            "throttle_wait_ms_total": mode_throttle_wait_ms,
+            "history": history_json
         });
-        
+
         test_cases.push(test_case);
     }
     
@@ -1,7 +1,11 @@
 use std::env;
 use std::fs;
+use std::io::Read;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use flate2::read::{GzDecoder, ZlibDecoder};
 use serde::{Deserialize, Serialize};
 use serde_json;
 
@@ -17,6 +21,60 @@ struct Parameters {
     timeout: Option<u64>,
     methods: Option<Vec<String>>,
     concurrent_requests: Option<u32>,
+    /// `"json"` (default) prints `serde_json::to_string_pretty`; `"markdown"`
+    /// instead renders an aligned results table, for pasting straight into a
+    /// PR or comparison write-up.
+    output_format: Option<String>,
+    /// Whether to send `Accept-Encoding: gzip, br` and decompress the body
+    /// with `decompress_body` (default `true`). Set to `false`
+    /// to measure uncompressed transfer instead, so compressed-vs-plain
+    /// bandwidth and CPU cost can be compared side by side.
+    decode_compression: Option<bool>,
+    /// Which TLS backend reqwest should use: `"native"` (the platform's
+    /// native-tls, the default) or `"rustls"`. Lets the benchmark compare
+    /// the two stacks' handshake cost head to head.
+    tls_backend: Option<String>,
+    /// Whether to verify server certificates (default `true`). Set to
+    /// `false` to accept invalid/self-signed certs, e.g. against a local
+    /// test server.
+    verify_certs: Option<bool>,
+    /// Number of requests per URL and method to run and discard before timing begins,
+    /// to burn off cold-start effects (connection setup, allocator
+    /// warm-up) that would otherwise pollute the reported averages.
+    /// Defaults to 0 (no warm-up).
+    warmup_iterations: Option<u32>,
+    /// Fraction (`0.0..=0.49`) of samples to discard from each end of the
+    /// sorted response times before averaging, to reduce sensitivity to
+    /// occasional cold-start/GC-style outliers. Defaults to `0.0` (no
+    /// trimming).
+    trim_percent: Option<f64>,
+}
+
+/// Renders `headers`/`rows` as a GitHub-flavored Markdown table: a header
+/// row, a `---` separator row, then one row per `rows` entry, with every
+/// column padded to its widest cell so the raw source lines up too.
+fn render_markdown_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.len());
+        }
+    }
+
+    let pad = |s: &str, w: usize| format!("{:<width$}", s, width = w);
+
+    let mut out = String::new();
+    out.push_str("| ");
+    out.push_str(&headers.iter().zip(&widths).map(|(h, &w)| pad(h, w)).collect::<Vec<_>>().join(" | "));
+    out.push_str(" |\n| ");
+    out.push_str(&widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>().join(" | "));
+    out.push_str(" |\n");
+    for row in rows {
+        out.push_str("| ");
+        out.push_str(&row.iter().zip(&widths).map(|(c, &w)| pad(c, w)).collect::<Vec<_>>().join(" | "));
+        out.push_str(" |\n");
+    }
+    out
 }
 
 #[derive(Serialize)]
@@ -25,6 +83,27 @@ struct RequestResult {
     response_time: f64,
     status_code: u16,
     content_length: usize,
+    /// `Content-Encoding` header value (e.g. `"gzip"`, `"br"`), or `None`
+    /// when the response carried no `Content-Encoding` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<String>,
+    /// Bytes on the wire, from the response's `Content-Length` header.
+    /// `None` when the server didn't send one (e.g. chunked transfer).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    raw_content_length: Option<usize>,
+    /// Bytes after decompression, i.e. `content_length` of the body we
+    /// actually read. Equal to `raw_content_length` when the response
+    /// wasn't compressed.
+    decoded_content_length: usize,
+    /// Connection setup, TLS handshake, and request/response-header time,
+    /// up to the first response byte. reqwest's blocking client doesn't
+    /// expose connect and handshake as separate hooks, so the two are
+    /// combined here rather than guessed at.
+    time_to_first_byte_ms: f64,
+    /// Time spent downloading and (if applicable) decompressing the body,
+    /// isolated from `time_to_first_byte_ms` so compression/TLS-backend
+    /// cost can be attributed to the right phase.
+    body_read_time_ms: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
@@ -33,6 +112,10 @@ struct RequestResult {
 struct UrlResults {
     requests: Vec<RequestResult>,
     avg_response_time: f64,
+    p50_response_time: f64,
+    p90_response_time: f64,
+    p95_response_time: f64,
+    p99_response_time: f64,
     success_rate: f64,
     total_requests: u32,
     successful_requests: u32,
@@ -46,7 +129,46 @@ struct Summary {
     avg_response_time: f64,
     min_response_time: f64,
     max_response_time: f64,
+    p50_response_time: f64,
+    p90_response_time: f64,
+    p95_response_time: f64,
+    p99_response_time: f64,
     success_rate: f64,
+    requests_per_second: f64,
+}
+
+/// Sorts a copy of `times` and discards the bottom and top `trim_pct`
+/// fraction of samples from each end (e.g. `0.1` for a 10% trim), returning
+/// the retained, still-sorted samples. `trim_pct` is clamped to `0.0..=0.49`
+/// so at least one sample always survives; `0.0` (the default) keeps every
+/// sample.
+fn trim_samples(times: &[f64], trim_pct: f64) -> Vec<f64> {
+    if times.is_empty() {
+        return Vec::new();
+    }
+    let mut sorted = times.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let trim_count = (sorted.len() as f64 * trim_pct.clamp(0.0, 0.49)).floor() as usize;
+    let end = sorted.len() - trim_count;
+    if trim_count < end {
+        sorted[trim_count..end].to_vec()
+    } else {
+        sorted
+    }
+}
+
+/// Nearest-rank percentile over already-collected response times. `times`
+/// need not be sorted; this sorts its own copy rather than requiring callers
+/// to maintain a sorted vector. `p` is in `0.0..=100.0`.
+fn percentile(times: &[f64], p: f64) -> f64 {
+    if times.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = times.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    let idx = rank.saturating_sub(1).min(sorted.len() - 1);
+    sorted[idx]
 }
 
 #[derive(Serialize)]
@@ -58,13 +180,49 @@ struct Results {
     total_execution_time: f64,
 }
 
-fn make_http_request(url: &str, method: &str, timeout_ms: u64) -> RequestResult {
+/// Decompresses `raw` per the response's `Content-Encoding`, falling back to
+/// the raw bytes unchanged if the encoding is unrecognized or decoding
+/// fails (so a corrupt/odd body still gets counted rather than dropped).
+fn decompress_body(encoding: Option<&str>, raw: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let decoded = match encoding {
+        Some("gzip") => GzDecoder::new(raw).read_to_end(&mut out),
+        Some("deflate") => ZlibDecoder::new(raw).read_to_end(&mut out),
+        Some("br") => brotli::Decompressor::new(raw, 4096).read_to_end(&mut out),
+        _ => return raw.to_vec(),
+    };
+    match decoded {
+        Ok(_) => out,
+        Err(_) => raw.to_vec(),
+    }
+}
+
+fn make_http_request(
+    url: &str,
+    method: &str,
+    timeout_ms: u64,
+    decode_compression: bool,
+    tls_backend: &str,
+    verify_certs: bool,
+) -> RequestResult {
     let start_time = Instant::now();
-    
-    let client = reqwest::blocking::Client::builder()
+
+    // Compression is decoded manually below (see `decompress_body`) rather than via
+    // reqwest's `.gzip()/.brotli()` builder flags: those install a decoding reader that
+    // strips the `Content-Encoding`/`Content-Length` response headers before this function
+    // ever sees them, which defeats measuring wire size vs. decoded size. Both must be
+    // explicitly disabled: reqwest auto-decompresses by default whenever the gzip/brotli
+    // cargo features are compiled in, regardless of whether `.gzip()/.brotli()` is called.
+    let mut builder = reqwest::blocking::Client::builder()
         .timeout(Duration::from_millis(timeout_ms))
-        .danger_accept_invalid_certs(true)
-        .build();
+        .danger_accept_invalid_certs(!verify_certs)
+        .gzip(false)
+        .brotli(false);
+    builder = match tls_backend {
+        "rustls" => builder.use_rustls_tls(),
+        _ => builder.use_native_tls(),
+    };
+    let client = builder.build();
 
     let client = match client {
         Ok(c) => c,
@@ -75,6 +233,11 @@ fn make_http_request(url: &str, method: &str, timeout_ms: u64) -> RequestResult
                 response_time,
                 status_code: 0,
                 content_length: 0,
+                encoding: None,
+                raw_content_length: None,
+                decoded_content_length: 0,
+                time_to_first_byte_ms: 0.0,
+                body_read_time_ms: 0.0,
                 error: Some(format!("Client creation error: {}", e)),
             };
         }
@@ -89,28 +252,68 @@ fn make_http_request(url: &str, method: &str, timeout_ms: u64) -> RequestResult
     };
 
     let request = request_builder.header("User-Agent", "BenchmarkTool/1.0");
+    let request = if decode_compression {
+        request.header(reqwest::header::ACCEPT_ENCODING, "gzip, br")
+    } else {
+        request
+    };
 
+    let send_start = Instant::now();
     match request.send() {
         Ok(response) => {
-            let response_time = start_time.elapsed().as_millis() as f64;
+            let time_to_first_byte_ms = send_start.elapsed().as_secs_f64() * 1000.0;
             let status_code = response.status().as_u16();
             let is_success = response.status().is_success();
-            
-            match response.text() {
-                Ok(content) => RequestResult {
-                    success: is_success,
-                    response_time,
-                    status_code,
-                    content_length: content.len(),
-                    error: if is_success { None } else { Some(format!("HTTP Error {}", status_code)) },
-                },
-                Err(e) => RequestResult {
-                    success: false,
-                    response_time,
-                    status_code,
-                    content_length: 0,
-                    error: Some(format!("Content read error: {}", e)),
-                },
+            let encoding = response
+                .headers()
+                .get(reqwest::header::CONTENT_ENCODING)
+                .and_then(|v| v.to_str().ok())
+                .map(|s| s.to_string());
+            let raw_content_length = response
+                .headers()
+                .get(reqwest::header::CONTENT_LENGTH)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|s| s.parse::<usize>().ok());
+
+            let body_start = Instant::now();
+            match response.bytes() {
+                Ok(raw) => {
+                    let decoded = if decode_compression {
+                        decompress_body(encoding.as_deref(), &raw)
+                    } else {
+                        raw.to_vec()
+                    };
+                    let body_read_time_ms = body_start.elapsed().as_secs_f64() * 1000.0;
+                    let response_time = start_time.elapsed().as_millis() as f64;
+                    RequestResult {
+                        success: is_success,
+                        response_time,
+                        status_code,
+                        content_length: decoded.len(),
+                        encoding,
+                        raw_content_length,
+                        decoded_content_length: decoded.len(),
+                        time_to_first_byte_ms,
+                        body_read_time_ms,
+                        error: if is_success { None } else { Some(format!("HTTP Error {}", status_code)) },
+                    }
+                }
+                Err(e) => {
+                    let body_read_time_ms = body_start.elapsed().as_secs_f64() * 1000.0;
+                    let response_time = start_time.elapsed().as_millis() as f64;
+                    RequestResult {
+                        success: false,
+                        response_time,
+                        status_code,
+                        content_length: 0,
+                        encoding,
+                        raw_content_length,
+                        decoded_content_length: 0,
+                        time_to_first_byte_ms,
+                        body_read_time_ms,
+                        error: Some(format!("Content read error: {}", e)),
+                    }
+                }
             }
         }
         Err(e) => {
@@ -120,12 +323,65 @@ fn make_http_request(url: &str, method: &str, timeout_ms: u64) -> RequestResult
                 response_time,
                 status_code: 0,
                 content_length: 0,
+                encoding: None,
+                raw_content_length: None,
+                decoded_content_length: 0,
+                time_to_first_byte_ms: send_start.elapsed().as_secs_f64() * 1000.0,
+                body_read_time_ms: 0.0,
                 error: Some(e.to_string()),
             }
         }
     }
 }
 
+/// Issues one request per (method, index) job in `methods x request_count`
+/// across `concurrent_requests` worker threads sharing a `Mutex`-guarded
+/// job queue, so the URL sees real concurrent load instead of one
+/// sequential stream. Results are sent back over an `mpsc` channel and can
+/// arrive in any order; every caller aggregates them with order-independent
+/// reductions (sum/count/min/max), so that's fine.
+fn run_requests_concurrently(
+    url: &str,
+    methods: &[String],
+    request_count: u32,
+    concurrent_requests: u32,
+    timeout: u64,
+    decode_compression: bool,
+    tls_backend: &str,
+    verify_certs: bool,
+) -> Vec<RequestResult> {
+    let jobs: VecDeque<String> = methods
+        .iter()
+        .flat_map(|m| std::iter::repeat(m.clone()).take(request_count as usize))
+        .collect();
+    let jobs = Mutex::new(jobs);
+    let worker_count = concurrent_requests.max(1) as usize;
+
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..worker_count {
+            let tx = tx.clone();
+            let jobs = &jobs;
+            scope.spawn(move || {
+                loop {
+                    let method = {
+                        let mut jobs = jobs.lock().unwrap();
+                        jobs.pop_front()
+                    };
+                    let Some(method) = method else { break };
+                    let result =
+                        make_http_request(url, &method, timeout, decode_compression, tls_backend, verify_certs);
+                    tx.send(result).expect("result channel closed unexpectedly");
+                }
+            });
+        }
+        drop(tx);
+    });
+
+    rx.iter().collect()
+}
+
 fn run_http_benchmark(params: &Parameters) -> Results {
     let start_time = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -135,20 +391,42 @@ fn run_http_benchmark(params: &Parameters) -> Results {
     let request_count = params.request_count.unwrap_or(5);
     let timeout = params.timeout.unwrap_or(10000);
     let methods = params.methods.as_ref().map(|m| m.clone()).unwrap_or_else(|| vec!["GET".to_string()]);
+    let concurrent_requests = params.concurrent_requests.unwrap_or(1);
+    let decode_compression = params.decode_compression.unwrap_or(true);
+    let tls_backend = params.tls_backend.as_deref().unwrap_or("native");
+    let verify_certs = params.verify_certs.unwrap_or(true);
+    let warmup_iterations = params.warmup_iterations.unwrap_or(0);
+    let trim_percent = params.trim_percent.unwrap_or(0.0);
 
     let mut urls_results = HashMap::new();
     let mut total_requests = 0u32;
     let mut successful_requests = 0u32;
-    let mut total_response_time = 0.0;
-    let mut min_response_time = f64::INFINITY;
-    let mut max_response_time: f64 = 0.0;
+    let mut all_response_times = Vec::new();
 
     for url in &params.urls {
-        eprintln!("Testing {}...", url);
+        eprintln!("Testing {} ({} concurrent workers)...", url, concurrent_requests);
+
+        if warmup_iterations > 0 {
+            eprintln!("  Warming up with {} request(s) per method (discarded)...", warmup_iterations);
+            run_requests_concurrently(
+                url,
+                &methods,
+                warmup_iterations,
+                concurrent_requests,
+                timeout,
+                decode_compression,
+                tls_backend,
+                verify_certs,
+            );
+        }
 
         let mut url_results = UrlResults {
             requests: Vec::new(),
             avg_response_time: 0.0,
+            p50_response_time: 0.0,
+            p90_response_time: 0.0,
+            p95_response_time: 0.0,
+            p99_response_time: 0.0,
             success_rate: 0.0,
             total_requests: 0,
             successful_requests: 0,
@@ -157,28 +435,28 @@ fn run_http_benchmark(params: &Parameters) -> Results {
         let mut url_response_times = Vec::new();
         let mut url_successful = 0u32;
 
-        for method in &methods {
-            for i in 0..request_count {
-                eprintln!("  Request {}/{} ({})...", i + 1, request_count, method);
-
-                let request_result = make_http_request(url, method, timeout);
-                
-                total_requests += 1;
-                url_results.total_requests += 1;
-
-                if request_result.success {
-                    successful_requests += 1;
-                    url_successful += 1;
-
-                    let response_time = request_result.response_time;
-                    url_response_times.push(response_time);
-                    total_response_time += response_time;
-                    min_response_time = min_response_time.min(response_time);
-                    max_response_time = max_response_time.max(response_time);
-                }
-
-                url_results.requests.push(request_result);
+        let request_results = run_requests_concurrently(
+            url,
+            &methods,
+            request_count,
+            concurrent_requests,
+            timeout,
+            decode_compression,
+            tls_backend,
+            verify_certs,
+        );
+
+        for request_result in request_results {
+            total_requests += 1;
+            url_results.total_requests += 1;
+
+            if request_result.success {
+                successful_requests += 1;
+                url_successful += 1;
+                url_response_times.push(request_result.response_time);
             }
+
+            url_results.requests.push(request_result);
         }
 
         url_results.successful_requests = url_successful;
@@ -188,10 +466,16 @@ fn run_http_benchmark(params: &Parameters) -> Results {
             0.0
         };
 
-        if !url_response_times.is_empty() {
-            url_results.avg_response_time = url_response_times.iter().sum::<f64>() / url_response_times.len() as f64;
+        let retained = trim_samples(&url_response_times, trim_percent);
+        if !retained.is_empty() {
+            url_results.avg_response_time = retained.iter().sum::<f64>() / retained.len() as f64;
+            url_results.p50_response_time = percentile(&retained, 50.0);
+            url_results.p90_response_time = percentile(&retained, 90.0);
+            url_results.p95_response_time = percentile(&retained, 95.0);
+            url_results.p99_response_time = percentile(&retained, 99.0);
         }
 
+        all_response_times.extend_from_slice(&url_response_times);
         urls_results.insert(url.clone(), url_results);
     }
 
@@ -201,21 +485,27 @@ fn run_http_benchmark(params: &Parameters) -> Results {
         0.0
     };
 
-    let avg_response_time = if successful_requests > 0 {
-        total_response_time / successful_requests as f64
+    let retained_all = trim_samples(&all_response_times, trim_percent);
+    let avg_response_time = if !retained_all.is_empty() {
+        retained_all.iter().sum::<f64>() / retained_all.len() as f64
     } else {
         0.0
     };
-
-    if min_response_time == f64::INFINITY {
-        min_response_time = 0.0;
-    }
+    let min_response_time = retained_all.first().copied().unwrap_or(0.0);
+    let max_response_time = retained_all.last().copied().unwrap_or(0.0);
 
     let end_time = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
         .as_secs_f64();
 
+    let total_execution_time = end_time - start_time;
+    let requests_per_second = if total_execution_time > 0.0 {
+        total_requests as f64 / total_execution_time
+    } else {
+        0.0
+    };
+
     Results {
         start_time,
         urls: urls_results,
@@ -226,10 +516,15 @@ fn run_http_benchmark(params: &Parameters) -> Results {
             avg_response_time,
             min_response_time,
             max_response_time,
+            p50_response_time: percentile(&retained_all, 50.0),
+            p90_response_time: percentile(&retained_all, 90.0),
+            p95_response_time: percentile(&retained_all, 95.0),
+            p99_response_time: percentile(&retained_all, 99.0),
             success_rate,
+            requests_per_second,
         },
         end_time,
-        total_execution_time: end_time - start_time,
+        total_execution_time,
     }
 }
 
@@ -244,8 +539,31 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config_content = fs::read_to_string(config_file)?;
     let config: Config = serde_json::from_str(&config_content)?;
 
+    let output_format = config.parameters.output_format.clone().unwrap_or_else(|| "json".to_string());
     let results = run_http_benchmark(&config.parameters);
-    println!("{}", serde_json::to_string_pretty(&results)?);
+
+    if output_format == "markdown" {
+        let headers = ["URL", "Requests", "Success %", "Avg (ms)", "p50 (ms)", "p90 (ms)", "p99 (ms)"];
+        let mut rows: Vec<Vec<String>> = results
+            .urls
+            .iter()
+            .map(|(url, r)| {
+                vec![
+                    url.clone(),
+                    r.total_requests.to_string(),
+                    format!("{:.1}", r.success_rate),
+                    format!("{:.2}", r.avg_response_time),
+                    format!("{:.2}", r.p50_response_time),
+                    format!("{:.2}", r.p90_response_time),
+                    format!("{:.2}", r.p99_response_time),
+                ]
+            })
+            .collect();
+        rows.sort();
+        print!("{}", render_markdown_table(&headers, &rows));
+    } else {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    }
 
     Ok(())
 }
\ No newline at end of file
@@ -7,10 +7,14 @@ use serde_json;
 use regex::Regex;
 use std::thread;
 use std::sync::{Arc, Mutex};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
 
 #[derive(Deserialize)]
 struct Config {
     parameters: Parameters,
+    format: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -18,6 +22,17 @@ struct Parameters {
     targets: Vec<String>,
     packet_count: Option<u32>,
     timeout: Option<u32>,
+    /// Per-target overrides keyed by the target string, for picking a
+    /// non-ICMP mode (`"tcp_connect"` or `"bandwidth"`) and the port it
+    /// connects to. Targets absent from this map fall back to the
+    /// original ICMP `ping` path.
+    target_options: Option<std::collections::HashMap<String, TargetOptions>>,
+}
+
+#[derive(Deserialize, Clone)]
+struct TargetOptions {
+    mode: Option<String>,
+    port: Option<u16>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -27,16 +42,98 @@ struct PingResult {
     max_latency: f64,
     packet_loss: f64,
     execution_time: f64,
+    samples: Vec<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statistics: Option<Statistics>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    throughput_mbps: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
+/// Hyperfine-style summary of a sample vector: spread (stddev, min/max),
+/// shape (median, coefficient of variation), and IQR-based outlier
+/// detection (anything outside `Q1 - 1.5*IQR .. Q3 + 1.5*IQR`).
+/// `first_sample_is_outlier` flags a cold-cache/warmup artifact
+/// specifically: the very first sample being an outlier relative to the
+/// rest suggests the caller should add warmup runs.
+#[derive(Serialize, Clone, Debug)]
+struct Statistics {
+    mean: f64,
+    stddev: f64,
+    median: f64,
+    min: f64,
+    max: f64,
+    coefficient_of_variation: f64,
+    outliers: Vec<f64>,
+    first_sample_is_outlier: bool,
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p / 100.0) * (sorted.len() as f64 - 1.0);
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+    }
+}
+
+fn compute_statistics(samples: &[f64]) -> Option<Statistics> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len();
+
+    let mean = sorted.iter().sum::<f64>() / n as f64;
+    let stddev = if n > 1 {
+        let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        variance.sqrt()
+    } else {
+        0.0
+    };
+
+    let q1 = percentile(&sorted, 25.0);
+    let q3 = percentile(&sorted, 75.0);
+    let iqr = q3 - q1;
+    let lower_fence = q1 - 1.5 * iqr;
+    let upper_fence = q3 + 1.5 * iqr;
+
+    let outliers: Vec<f64> = samples
+        .iter()
+        .copied()
+        .filter(|&v| v < lower_fence || v > upper_fence)
+        .collect();
+    let first_sample_is_outlier = samples[0] < lower_fence || samples[0] > upper_fence;
+
+    Some(Statistics {
+        mean,
+        stddev,
+        median: percentile(&sorted, 50.0),
+        min: sorted[0],
+        max: sorted[n - 1],
+        coefficient_of_variation: if mean != 0.0 { stddev / mean } else { 0.0 },
+        outliers,
+        first_sample_is_outlier,
+    })
+}
+
 #[derive(Serialize)]
 struct Summary {
     total_targets: usize,
     successful_targets: usize,
     failed_targets: usize,
     overall_avg_latency: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statistics: Option<Statistics>,
 }
 
 #[derive(Serialize)]
@@ -44,10 +141,202 @@ struct Results {
     start_time: f64,
     targets: std::collections::HashMap<String, PingResult>,
     summary: Summary,
+    network_delta: NetworkDelta,
     end_time: f64,
     total_execution_time: f64,
 }
 
+/// Per-interface counters read from `/proc/net/dev` for whichever
+/// interface the default route points at.
+#[derive(Clone, Debug, Default)]
+struct InterfaceCounters {
+    interface: String,
+    rx_bytes: u64,
+    rx_packets: u64,
+    rx_errors: u64,
+    rx_drops: u64,
+    tx_bytes: u64,
+    tx_packets: u64,
+    tx_errors: u64,
+    tx_drops: u64,
+}
+
+/// A point-in-time snapshot of kernel network counters: the default
+/// egress interface's `/proc/net/dev` row plus UDP error counters from
+/// `/proc/net/snmp`. Taken before and after a benchmark run and diffed,
+/// so elevated `packet_loss` can be checked against local NIC drops
+/// rather than assumed to be path loss.
+#[derive(Clone, Debug, Default)]
+struct NetworkCounters {
+    interface: Option<InterfaceCounters>,
+    udp_in_errors: u64,
+    udp_rcvbuf_errors: u64,
+    udp_sndbuf_errors: u64,
+    note: Option<String>,
+}
+
+/// The deltas between two `NetworkCounters` snapshots, covering the
+/// benchmark's measurement window.
+#[derive(Serialize, Clone, Debug, Default)]
+struct NetworkDelta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interface: Option<String>,
+    rx_bytes: u64,
+    rx_packets: u64,
+    rx_errors: u64,
+    rx_drops: u64,
+    tx_bytes: u64,
+    tx_packets: u64,
+    tx_errors: u64,
+    tx_drops: u64,
+    udp_in_errors: u64,
+    udp_rcvbuf_errors: u64,
+    udp_sndbuf_errors: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+}
+
+/// The interface the default route (destination `0.0.0.0`) points at,
+/// picking the lowest-metric candidate if more than one default route
+/// exists.
+#[cfg(target_os = "linux")]
+fn default_egress_interface() -> Option<String> {
+    let contents = std::fs::read_to_string("/proc/net/route").ok()?;
+    contents
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 7 || fields[1] != "00000000" {
+                return None;
+            }
+            let metric: u32 = fields[6].parse().ok()?;
+            Some((fields[0].to_string(), metric))
+        })
+        .min_by_key(|(_, metric)| *metric)
+        .map(|(iface, _)| iface)
+}
+
+#[cfg(target_os = "linux")]
+fn read_interface_counters(iface: &str) -> Option<InterfaceCounters> {
+    let contents = std::fs::read_to_string("/proc/net/dev").ok()?;
+    for line in contents.lines().skip(2) {
+        let (name, rest) = line.split_once(':')?;
+        if name.trim() != iface {
+            continue;
+        }
+        let fields: Vec<u64> = rest.split_whitespace().filter_map(|s| s.parse().ok()).collect();
+        if fields.len() < 12 {
+            return None;
+        }
+        return Some(InterfaceCounters {
+            interface: iface.to_string(),
+            rx_bytes: fields[0],
+            rx_packets: fields[1],
+            rx_errors: fields[2],
+            rx_drops: fields[3],
+            tx_bytes: fields[8],
+            tx_packets: fields[9],
+            tx_errors: fields[10],
+            tx_drops: fields[11],
+        });
+    }
+    None
+}
+
+/// `InErrors`/`RcvbufErrors`/`SndbufErrors` from the `Udp:` section of
+/// `/proc/net/snmp`. Looked up by column name against the preceding `Udp:`
+/// header line rather than a fixed index, since the column set has grown
+/// across kernel versions.
+#[cfg(target_os = "linux")]
+fn read_snmp_udp_errors() -> (u64, u64, u64) {
+    let contents = match std::fs::read_to_string("/proc/net/snmp") {
+        Ok(c) => c,
+        Err(_) => return (0, 0, 0),
+    };
+
+    let mut header: Option<Vec<String>> = None;
+    for line in contents.lines() {
+        if !line.starts_with("Udp:") {
+            continue;
+        }
+        let fields: Vec<String> = line.split_whitespace().skip(1).map(|s| s.to_string()).collect();
+        match header.take() {
+            None => header = Some(fields),
+            Some(names) => {
+                let get = |name: &str| -> u64 {
+                    names
+                        .iter()
+                        .position(|n| n == name)
+                        .and_then(|idx| fields.get(idx))
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(0)
+                };
+                return (get("InErrors"), get("RcvbufErrors"), get("SndbufErrors"));
+            }
+        }
+    }
+    (0, 0, 0)
+}
+
+#[cfg(target_os = "linux")]
+fn snapshot_network_counters() -> NetworkCounters {
+    let interface = default_egress_interface().and_then(|iface| read_interface_counters(&iface));
+    let (udp_in_errors, udp_rcvbuf_errors, udp_sndbuf_errors) = read_snmp_udp_errors();
+    NetworkCounters {
+        interface,
+        udp_in_errors,
+        udp_rcvbuf_errors,
+        udp_sndbuf_errors,
+        note: None,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn snapshot_network_counters() -> NetworkCounters {
+    NetworkCounters {
+        interface: None,
+        udp_in_errors: 0,
+        udp_rcvbuf_errors: 0,
+        udp_sndbuf_errors: 0,
+        note: Some("Interface/UDP counters need GetIfTable2 on this platform, which isn't wired up here".to_string()),
+    }
+}
+
+fn diff_network_counters(before: &NetworkCounters, after: &NetworkCounters) -> NetworkDelta {
+    let (interface, rx_bytes, rx_packets, rx_errors, rx_drops, tx_bytes, tx_packets, tx_errors, tx_drops) =
+        match (&before.interface, &after.interface) {
+            (Some(b), Some(a)) if b.interface == a.interface => (
+                Some(a.interface.clone()),
+                a.rx_bytes.saturating_sub(b.rx_bytes),
+                a.rx_packets.saturating_sub(b.rx_packets),
+                a.rx_errors.saturating_sub(b.rx_errors),
+                a.rx_drops.saturating_sub(b.rx_drops),
+                a.tx_bytes.saturating_sub(b.tx_bytes),
+                a.tx_packets.saturating_sub(b.tx_packets),
+                a.tx_errors.saturating_sub(b.tx_errors),
+                a.tx_drops.saturating_sub(b.tx_drops),
+            ),
+            _ => (after.interface.as_ref().map(|i| i.interface.clone()), 0, 0, 0, 0, 0, 0, 0, 0),
+        };
+
+    NetworkDelta {
+        interface,
+        rx_bytes,
+        rx_packets,
+        rx_errors,
+        rx_drops,
+        tx_bytes,
+        tx_packets,
+        tx_errors,
+        tx_drops,
+        udp_in_errors: after.udp_in_errors.saturating_sub(before.udp_in_errors),
+        udp_rcvbuf_errors: after.udp_rcvbuf_errors.saturating_sub(before.udp_rcvbuf_errors),
+        udp_sndbuf_errors: after.udp_sndbuf_errors.saturating_sub(before.udp_sndbuf_errors),
+        note: after.note.clone(),
+    }
+}
+
 fn ping_host(host: &str, count: u32, timeout: u32) -> PingResult {
     let start_time = Instant::now();
     
@@ -79,6 +368,9 @@ fn ping_host(host: &str, count: u32, timeout: u32) -> PingResult {
                     max_latency: f64::INFINITY,
                     packet_loss: 100.0,
                     execution_time,
+                    samples: Vec::new(),
+                    statistics: None,
+                    throughput_mbps: None,
                     error: Some(if stderr.is_empty() { "Ping failed".to_string() } else { stderr.to_string() }),
                 }
             }
@@ -89,6 +381,9 @@ fn ping_host(host: &str, count: u32, timeout: u32) -> PingResult {
             max_latency: f64::INFINITY,
             packet_loss: 100.0,
             execution_time,
+            samples: Vec::new(),
+            statistics: None,
+            throughput_mbps: None,
             error: Some(e.to_string()),
         },
     }
@@ -101,6 +396,9 @@ fn parse_ping_output(output: &str) -> PingResult {
         max_latency: 0.0,
         packet_loss: 0.0,
         execution_time: 0.0,
+        samples: Vec::new(),
+        statistics: None,
+        throughput_mbps: None,
         error: None,
     };
 
@@ -124,6 +422,7 @@ fn parse_ping_output(output: &str) -> PingResult {
             result.min_latency = times.iter().cloned().fold(f64::INFINITY, f64::min);
             result.max_latency = times.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
             result.avg_latency = times.iter().sum::<f64>() / times.len() as f64;
+            result.samples = times;
         }
 
         // Try to get statistics from summary lines (supports English and French)
@@ -154,6 +453,13 @@ fn parse_ping_output(output: &str) -> PingResult {
             }
         }
 
+        // Per-packet round-trip times, e.g. "64 bytes from host: icmp_seq=1 ttl=64 time=0.123 ms"
+        let time_regex = Regex::new(r"time=([\d.]+) ms").unwrap();
+        result.samples = time_regex
+            .captures_iter(output)
+            .filter_map(|cap| cap[1].parse().ok())
+            .collect();
+
         // Parse rtt statistics
         if let Some(captures) = Regex::new(r"rtt min/avg/max/mdev = ([\d.]+)/([\d.]+)/([\d.]+)/([\d.]+) ms").unwrap().captures(output) {
             if let (Ok(min), Ok(avg), Ok(max)) = (
@@ -173,9 +479,185 @@ fn parse_ping_output(output: &str) -> PingResult {
         result.error = Some("Failed to parse ping output".to_string());
     }
 
+    result.statistics = compute_statistics(&result.samples);
+
     result
 }
 
+/// Measures `TcpStream::connect` handshake latency directly, without
+/// shelling out to `ping` or scraping localized stdout. Each sample is one
+/// connect attempt; a failed or timed-out attempt counts toward
+/// `packet_loss` rather than latency.
+fn tcp_connect_latency(host: &str, port: u16, count: u32, timeout_ms: u32) -> PingResult {
+    let addr = format!("{}:{}", host, port);
+    let mut samples = Vec::new();
+    let mut failures = 0u32;
+    let start_time = Instant::now();
+
+    for _ in 0..count {
+        let socket_addr = match addr.to_socket_addrs().ok().and_then(|mut it| it.next()) {
+            Some(a) => a,
+            None => {
+                failures += 1;
+                continue;
+            }
+        };
+
+        let attempt_start = Instant::now();
+        match TcpStream::connect_timeout(&socket_addr, Duration::from_millis(timeout_ms as u64)) {
+            Ok(_stream) => samples.push(attempt_start.elapsed().as_secs_f64() * 1000.0),
+            Err(_) => failures += 1,
+        }
+    }
+
+    let execution_time = start_time.elapsed().as_secs_f64();
+    let packet_loss = if count > 0 {
+        (failures as f64 / count as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    let (min_latency, max_latency, avg_latency) = if samples.is_empty() {
+        (f64::INFINITY, f64::INFINITY, f64::INFINITY)
+    } else {
+        (
+            samples.iter().cloned().fold(f64::INFINITY, f64::min),
+            samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            samples.iter().sum::<f64>() / samples.len() as f64,
+        )
+    };
+
+    PingResult {
+        avg_latency,
+        min_latency,
+        max_latency,
+        packet_loss,
+        execution_time,
+        statistics: compute_statistics(&samples),
+        samples,
+        throughput_mbps: None,
+        error: if failures == count && count > 0 {
+            Some("All TCP connect attempts failed".to_string())
+        } else {
+            None
+        },
+    }
+}
+
+/// Streams a fixed payload to a peer running this same binary in
+/// `--listen` mode, which echoes it straight back. Reports throughput in
+/// Mbps from the total bytes exchanged, and round-trip latency under load
+/// (send-then-await-echo) per chunk, the same way `tcp_connect_latency`
+/// reports per-attempt connect latency.
+fn bandwidth_test(host: &str, port: u16, timeout_ms: u32) -> PingResult {
+    const CHUNK_SIZE: usize = 65536;
+    const CHUNK_COUNT: usize = 64; // 4 MB of payload total
+
+    let addr = format!("{}:{}", host, port);
+    let socket_addr = match addr.to_socket_addrs().ok().and_then(|mut it| it.next()) {
+        Some(a) => a,
+        None => return bandwidth_error(format!("Could not resolve {}", addr)),
+    };
+
+    let mut stream = match TcpStream::connect_timeout(&socket_addr, Duration::from_millis(timeout_ms as u64)) {
+        Ok(s) => s,
+        Err(e) => return bandwidth_error(e.to_string()),
+    };
+    stream.set_nodelay(true).ok();
+
+    let payload = vec![0xABu8; CHUNK_SIZE];
+    let mut echo_buf = vec![0u8; CHUNK_SIZE];
+    let mut round_trips = Vec::new();
+    let mut total_bytes = 0u64;
+    let start_time = Instant::now();
+
+    for _ in 0..CHUNK_COUNT {
+        let chunk_start = Instant::now();
+        if stream.write_all(&payload).is_err() || stream.read_exact(&mut echo_buf).is_err() {
+            break;
+        }
+        round_trips.push(chunk_start.elapsed().as_secs_f64() * 1000.0);
+        total_bytes += CHUNK_SIZE as u64;
+    }
+
+    let elapsed = start_time.elapsed().as_secs_f64();
+    let throughput_mbps = if elapsed > 0.0 {
+        (total_bytes as f64 * 8.0 / 1_000_000.0) / elapsed
+    } else {
+        0.0
+    };
+
+    let (min_latency, max_latency, avg_latency) = if round_trips.is_empty() {
+        (f64::INFINITY, f64::INFINITY, f64::INFINITY)
+    } else {
+        (
+            round_trips.iter().cloned().fold(f64::INFINITY, f64::min),
+            round_trips.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            round_trips.iter().sum::<f64>() / round_trips.len() as f64,
+        )
+    };
+
+    let packet_loss = ((CHUNK_COUNT - round_trips.len()) as f64 / CHUNK_COUNT as f64) * 100.0;
+
+    PingResult {
+        avg_latency,
+        min_latency,
+        max_latency,
+        packet_loss,
+        execution_time: elapsed,
+        statistics: compute_statistics(&round_trips),
+        samples: round_trips,
+        throughput_mbps: Some(throughput_mbps),
+        error: if total_bytes == 0 {
+            Some("Bandwidth transfer exchanged no data".to_string())
+        } else {
+            None
+        },
+    }
+}
+
+fn bandwidth_error(message: String) -> PingResult {
+    PingResult {
+        avg_latency: f64::INFINITY,
+        min_latency: f64::INFINITY,
+        max_latency: f64::INFINITY,
+        packet_loss: 100.0,
+        execution_time: 0.0,
+        samples: Vec::new(),
+        statistics: None,
+        throughput_mbps: None,
+        error: Some(message),
+    }
+}
+
+/// Passive peer for `"bandwidth"` mode: accepts connections and echoes
+/// back whatever it's sent, so a `bandwidth_test` client has something to
+/// measure round-trip latency and throughput against.
+fn run_listen_mode(port: u16) -> std::io::Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", port))?;
+    eprintln!("Listening for bandwidth benchmark connections on port {}...", port);
+
+    for stream in listener.incoming() {
+        let mut stream = stream?;
+        thread::spawn(move || {
+            let mut buf = [0u8; 65536];
+            loop {
+                match stream.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(n) => {
+                        if stream.write_all(&buf[..n]).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+    }
+
+    Ok(())
+}
+
 fn run_ping_benchmark(params: &Parameters) -> Results {
     let start_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -185,15 +667,18 @@ fn run_ping_benchmark(params: &Parameters) -> Results {
     let packet_count = params.packet_count.unwrap_or(3); // Reduced for better performance
     let timeout = params.timeout.unwrap_or(3000); // Reduced for better performance
 
+    let network_before = snapshot_network_counters();
+
     let targets_arc = Arc::new(Mutex::new(std::collections::HashMap::new()));
     let successful_targets = Arc::new(Mutex::new(0));
     let failed_targets = Arc::new(Mutex::new(0));
     let total_latency = Arc::new(Mutex::new(0.0));
     let successful_count = Arc::new(Mutex::new(0));
+    let all_avg_latencies = Arc::new(Mutex::new(Vec::new()));
 
     // Execute pings concurrently for better performance
     let mut handles = vec![];
-    
+
     for target in &params.targets {
         let target_clone = target.clone();
         let targets_arc_clone = Arc::clone(&targets_arc);
@@ -201,37 +686,53 @@ fn run_ping_benchmark(params: &Parameters) -> Results {
         let failed_targets_clone = Arc::clone(&failed_targets);
         let total_latency_clone = Arc::clone(&total_latency);
         let successful_count_clone = Arc::clone(&successful_count);
-        
+        let all_avg_latencies_clone = Arc::clone(&all_avg_latencies);
+        let options = params
+            .target_options
+            .as_ref()
+            .and_then(|m| m.get(target))
+            .cloned();
+
         let handle = thread::spawn(move || {
-            eprintln!("Pinging {}...", target_clone);
-            
-            let ping_result = ping_host(&target_clone, packet_count, timeout);
-            
+            let mode = options.as_ref().and_then(|o| o.mode.clone()).unwrap_or_else(|| "icmp".to_string());
+            let port = options.as_ref().and_then(|o| o.port).unwrap_or(80);
+
+            eprintln!("Pinging {} (mode: {})...", target_clone, mode);
+
+            let ping_result = match mode.as_str() {
+                "tcp_connect" => tcp_connect_latency(&target_clone, port, packet_count, timeout),
+                "bandwidth" => bandwidth_test(&target_clone, port, timeout),
+                _ => ping_host(&target_clone, packet_count, timeout),
+            };
+
             {
                 let mut targets = targets_arc_clone.lock().unwrap();
                 targets.insert(target_clone.clone(), ping_result.clone());
             }
-            
+
             if ping_result.error.is_none() && ping_result.packet_loss < 100.0 {
                 let mut success_count = successful_targets_clone.lock().unwrap();
                 *success_count += 1;
-                
+
                 if ping_result.avg_latency.is_finite() {
                     let mut total = total_latency_clone.lock().unwrap();
                     *total += ping_result.avg_latency;
-                    
+
                     let mut count = successful_count_clone.lock().unwrap();
                     *count += 1;
+
+                    let mut latencies = all_avg_latencies_clone.lock().unwrap();
+                    latencies.push(ping_result.avg_latency);
                 }
             } else {
                 let mut fail_count = failed_targets_clone.lock().unwrap();
                 *fail_count += 1;
             }
         });
-        
+
         handles.push(handle);
     }
-    
+
     // Wait for all threads to complete
     for handle in handles {
         handle.join().unwrap();
@@ -247,6 +748,9 @@ fn run_ping_benchmark(params: &Parameters) -> Results {
         }
     };
 
+    let overall_statistics = compute_statistics(&all_avg_latencies.lock().unwrap());
+    let network_delta = diff_network_counters(&network_before, &snapshot_network_counters());
+
     let end_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
@@ -264,25 +768,116 @@ fn run_ping_benchmark(params: &Parameters) -> Results {
             successful_targets: successful_targets_count,
             failed_targets: failed_targets_count,
             overall_avg_latency,
+            statistics: overall_statistics,
         },
+        network_delta,
         end_time,
         total_execution_time: end_time - start_time,
     }
 }
 
+/// Renders `results` as a GitHub-flavored Markdown table, one row per
+/// target plus a bolded summary row, so it can be pasted directly into a
+/// PR or README instead of squinting at pretty-printed JSON. The `---:`
+/// header separators right-align the numeric columns per the Markdown
+/// spec. Targets are sorted by name so the row order is stable across
+/// runs of the same config.
+fn render_markdown(results: &Results) -> String {
+    let mut out = String::new();
+    out.push_str("| Target | Avg Latency (ms) | Min (ms) | Max (ms) | Packet Loss (%) | Throughput (Mbps) |\n");
+    out.push_str("|---|---:|---:|---:|---:|---:|\n");
+
+    let mut targets: Vec<&String> = results.targets.keys().collect();
+    targets.sort();
+    for target in targets {
+        let r = &results.targets[target];
+        out.push_str(&format!(
+            "| {} | {:.3} | {:.3} | {:.3} | {:.1} | {} |\n",
+            target,
+            r.avg_latency,
+            r.min_latency,
+            r.max_latency,
+            r.packet_loss,
+            r.throughput_mbps.map(|v| format!("{:.3}", v)).unwrap_or_else(|| "-".to_string()),
+        ));
+    }
+
+    out.push_str(&format!(
+        "| **Summary ({}/{})** | **{:.3}** | | | | |\n",
+        results.summary.successful_targets, results.summary.total_targets, results.summary.overall_avg_latency
+    ));
+
+    out
+}
+
+/// Renders `results` as CSV, one row per target plus a trailing summary
+/// row, for spreadsheet import.
+fn render_csv(results: &Results) -> String {
+    let mut out = String::new();
+    out.push_str("target,avg_latency_ms,min_latency_ms,max_latency_ms,packet_loss_pct,throughput_mbps\n");
+
+    let mut targets: Vec<&String> = results.targets.keys().collect();
+    targets.sort();
+    for target in targets {
+        let r = &results.targets[target];
+        out.push_str(&format!(
+            "{},{:.3},{:.3},{:.3},{:.1},{}\n",
+            target,
+            r.avg_latency,
+            r.min_latency,
+            r.max_latency,
+            r.packet_loss,
+            r.throughput_mbps.map(|v| format!("{:.3}", v)).unwrap_or_default(),
+        ));
+    }
+
+    out.push_str(&format!(
+        "summary,{:.3},,,,\n",
+        results.summary.overall_avg_latency
+    ));
+
+    out
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
+
+    if args.len() >= 3 && args[1] == "--listen" {
+        let port: u16 = args[2].parse()?;
+        run_listen_mode(port)?;
+        return Ok(());
+    }
+
     if args.len() < 2 {
-        eprintln!("Usage: {} <config_file>", args[0]);
+        eprintln!("Usage: {} <config_file> [--format json|markdown|csv]", args[0]);
+        eprintln!("       {} --listen <port>   (bandwidth-mode peer)", args[0]);
         std::process::exit(1);
     }
 
+    let mut format: Option<String> = None;
+    let mut i = 2;
+    while i < args.len() {
+        if args[i] == "--format" && i + 1 < args.len() {
+            format = Some(args[i + 1].clone());
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
     let config_file = &args[1];
     let config_content = fs::read_to_string(config_file)?;
     let config: Config = serde_json::from_str(&config_content)?;
 
+    let format = format.or_else(|| config.format.clone()).unwrap_or_else(|| "json".to_string());
+
     let results = run_ping_benchmark(&config.parameters);
-    println!("{}", serde_json::to_string_pretty(&results)?);
+
+    match format.as_str() {
+        "markdown" => println!("{}", render_markdown(&results)),
+        "csv" => println!("{}", render_csv(&results)),
+        _ => println!("{}", serde_json::to_string_pretty(&results)?),
+    }
 
     Ok(())
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::env;
 use std::fs::File;
 use std::io::BufReader;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH, Instant};
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
@@ -19,6 +21,7 @@ struct Parameters {
     allocation_counts: Vec<usize>,
     data_structures: Vec<String>,
     iterations: usize,
+    warmup_iterations: Option<usize>,
 }
 
 #[derive(Debug, Serialize)]
@@ -40,6 +43,24 @@ struct TestCase {
     avg_allocation_time: f64,
     avg_deallocation_time: f64,
     avg_memory_efficiency: f64,
+    allocation_time_stats: DistributionStats,
+    deallocation_time_stats: DistributionStats,
+}
+
+/// Distribution of a timing sample beyond its mean: spread (min/max/stddev),
+/// shape (median/p95/p99), and how many samples were thrown out as outliers
+/// (beyond median +/- 3 * MAD) before any of these were computed, so a
+/// single GC pause or OS scheduling hiccup doesn't dominate the reported
+/// numbers.
+#[derive(Debug, Serialize)]
+struct DistributionStats {
+    min: f64,
+    max: f64,
+    median: f64,
+    p95: f64,
+    p99: f64,
+    stddev: f64,
+    rejected_outliers: usize,
 }
 
 #[derive(Debug, Serialize)]
@@ -80,45 +101,190 @@ struct Summary {
     avg_allocation_time: f64,
     avg_deallocation_time: f64,
     avg_memory_efficiency: f64,
+    allocation_time_stats: DistributionStats,
+    deallocation_time_stats: DistributionStats,
+}
+
+static CURRENT_MEMORY: AtomicUsize = AtomicUsize::new(0);
+static PEAK_MEMORY: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator so every allocation this process makes is
+/// tallied into `CURRENT_MEMORY`/`PEAK_MEMORY`, giving the benchmark real
+/// byte counts instead of a placeholder.
+struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_growth(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT_MEMORY.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            let old_size = layout.size();
+            if new_size >= old_size {
+                record_growth(new_size - old_size);
+            } else {
+                CURRENT_MEMORY.fetch_sub(old_size - new_size, Ordering::Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Adds `size` to `CURRENT_MEMORY` and bumps `PEAK_MEMORY` up to match if the
+/// new total is a new high-water mark, via a compare-and-swap loop so it only
+/// ever grows.
+fn record_growth(size: usize) {
+    let current = CURRENT_MEMORY.fetch_add(size, Ordering::Relaxed) + size;
+    let mut peak = PEAK_MEMORY.load(Ordering::Relaxed);
+    while current > peak {
+        match PEAK_MEMORY.compare_exchange_weak(peak, current, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => break,
+            Err(observed) => peak = observed,
+        }
+    }
 }
 
-// Simple memory tracking (approximation)
+#[global_allocator]
+static GLOBAL: TrackingAllocator = TrackingAllocator;
+
 fn get_memory_usage() -> usize {
-    // In Rust, we'll use a simple approximation since direct memory monitoring is complex
-    // This is a placeholder - in real scenarios you'd use external crates like sysinfo
-    0 // Returning 0 as placeholder
+    CURRENT_MEMORY.load(Ordering::Relaxed)
 }
 
-fn allocate_arrays(size: usize, count: usize) -> Vec<Vec<i32>> {
-    let mut rng = rand::thread_rng();
-    let mut arrays = Vec::with_capacity(count);
-    
-    for _ in 0..count {
-        let mut array = Vec::with_capacity(size);
-        for _ in 0..size {
-            array.push(rng.gen_range(0..1000));
+/// Returns the high-water mark of `CURRENT_MEMORY` observed since the last
+/// call to this function, then resets the mark to the current usage so the
+/// next call reports growth relative to this point rather than all-time.
+fn get_peak_memory() -> usize {
+    let current = CURRENT_MEMORY.load(Ordering::Relaxed);
+    PEAK_MEMORY.swap(current, Ordering::Relaxed)
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    if n == 0 {
+        return 0.0;
+    }
+    if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    }
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p / 100.0) * (sorted.len() as f64 - 1.0);
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * frac
+    }
+}
+
+fn stddev(values: &[f64], mean: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    variance.sqrt()
+}
+
+/// Drops samples more than 3 median-absolute-deviations from the median,
+/// the same robust-outlier rule used elsewhere in statistics tooling
+/// (unlike a stddev-based cutoff, it isn't itself skewed by the outliers
+/// it's trying to detect). Returns the kept samples and how many were
+/// rejected.
+fn reject_outliers(values: &[f64]) -> (Vec<f64>, usize) {
+    if values.len() < 2 {
+        return (values.to_vec(), 0);
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let med = median(&sorted);
+
+    let mut deviations: Vec<f64> = sorted.iter().map(|v| (v - med).abs()).collect();
+    deviations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mad = median(&deviations);
+
+    if mad == 0.0 {
+        return (values.to_vec(), 0);
+    }
+
+    let threshold = 3.0 * mad;
+    let mut kept = Vec::with_capacity(values.len());
+    let mut rejected = 0;
+    for &v in values {
+        if (v - med).abs() <= threshold {
+            kept.push(v);
+        } else {
+            rejected += 1;
         }
-        arrays.push(array);
     }
-    
-    arrays
+    (kept, rejected)
+}
+
+/// Rejects outliers from `values`, then computes distribution stats plus the
+/// mean of the surviving samples (the latter feeds the existing `avg_*`
+/// fields so they stay consistent with the new stats).
+fn compute_stats(values: &[f64]) -> (DistributionStats, f64) {
+    let (kept, rejected_outliers) = reject_outliers(values);
+    let mut sorted = kept;
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mean = if !sorted.is_empty() {
+        sorted.iter().sum::<f64>() / sorted.len() as f64
+    } else {
+        0.0
+    };
+
+    let stats = DistributionStats {
+        min: sorted.first().copied().unwrap_or(0.0),
+        max: sorted.last().copied().unwrap_or(0.0),
+        median: median(&sorted),
+        p95: percentile(&sorted, 95.0),
+        p99: percentile(&sorted, 99.0),
+        stddev: stddev(&sorted, mean),
+        rejected_outliers,
+    };
+
+    (stats, mean)
 }
 
-fn allocate_hash_maps(size: usize, count: usize) -> Vec<HashMap<i32, i32>> {
+fn create_array_item(size: usize) -> Vec<i32> {
     let mut rng = rand::thread_rng();
-    let mut maps = Vec::with_capacity(count);
-    
-    for _ in 0..count {
-        let mut map = HashMap::with_capacity(size);
-        for _ in 0..size {
-            let key = rng.gen_range(0..size * 2) as i32;
-            let value = rng.gen_range(0..1000);
-            map.insert(key, value);
-        }
-        maps.push(map);
+    let mut array = Vec::with_capacity(size);
+    for _ in 0..size {
+        array.push(rng.gen_range(0..1000));
     }
-    
-    maps
+    array
+}
+
+fn create_hash_map_item(size: usize) -> HashMap<i32, i32> {
+    let mut rng = rand::thread_rng();
+    let mut map = HashMap::with_capacity(size);
+    for _ in 0..size {
+        let key = rng.gen_range(0..size as i32 * 2);
+        let value = rng.gen_range(0..1000);
+        map.insert(key, value);
+    }
+    map
 }
 
 #[derive(Clone)]
@@ -127,23 +293,174 @@ struct ListNode {
     next: Option<Box<ListNode>>,
 }
 
-fn allocate_linked_lists(size: usize, count: usize) -> Vec<Option<Box<ListNode>>> {
+fn create_linked_list_item(size: usize) -> Option<Box<ListNode>> {
     let mut rng = rand::thread_rng();
-    let mut lists = Vec::with_capacity(count);
-    
-    for _ in 0..count {
-        let mut head: Option<Box<ListNode>> = None;
-        for _ in 0..size {
-            let new_node = ListNode {
-                value: rng.gen_range(0..1000),
-                next: head,
-            };
-            head = Some(Box::new(new_node));
+    let mut head: Option<Box<ListNode>> = None;
+    for _ in 0..size {
+        let new_node = ListNode {
+            value: rng.gen_range(0..1000),
+            next: head,
+        };
+        head = Some(Box::new(new_node));
+    }
+    head
+}
+
+fn create_grid(size: usize) -> Vec<u8> {
+    let mut rng = rand::thread_rng();
+    (0..size * size).map(|_| rng.gen_range(0..8)).collect()
+}
+
+/// Scans `grid` (a `size`x`size` matrix of small codes) for connected
+/// clusters of matching orthogonal neighbors, allocating a fresh work list
+/// (popped from the end, so each fill runs depth-first) and visited mask
+/// for the scan so their churn is what gets timed,
+/// rather than the grid itself. Returns the number of clusters with at
+/// least 5 cells.
+fn flood_fill_clusters(grid: &[u8], size: usize) -> usize {
+    let mut visited: Vec<u8> = vec![0; size * size];
+    let mut queue: Vec<[usize; 2]> = Vec::new();
+    let mut clusters_found = 0usize;
+
+    for y in 0..size {
+        for x in 0..size {
+            let idx = y * size + x;
+            if visited[idx] != 0 {
+                continue;
+            }
+
+            let code = grid[idx];
+            visited[idx] = 1;
+            queue.push([x, y]);
+            let mut cluster_len = 0usize;
+
+            while let Some([cx, cy]) = queue.pop() {
+                cluster_len += 1;
+                let neighbors = [
+                    (cx.checked_sub(1), Some(cy)),
+                    (cx.checked_add(1).filter(|&v| v < size), Some(cy)),
+                    (Some(cx), cy.checked_sub(1)),
+                    (Some(cx), cy.checked_add(1).filter(|&v| v < size)),
+                ];
+                for (nx, ny) in neighbors {
+                    if let (Some(nx), Some(ny)) = (nx, ny) {
+                        let nidx = ny * size + nx;
+                        if visited[nidx] == 0 && grid[nidx] == code {
+                            visited[nidx] = 1;
+                            queue.push([nx, ny]);
+                        }
+                    }
+                }
+            }
+
+            if cluster_len >= 5 {
+                clusters_found += 1;
+            }
         }
-        lists.push(head);
     }
-    
-    lists
+
+    clusters_found
+}
+
+/// Runs `count` allocations of whatever `create` produces through one of the
+/// four allocation/deallocation patterns named in `Parameters::allocation_patterns`,
+/// returning the total time spent allocating and the total time spent
+/// deallocating across every step of the pattern (everything created is
+/// dropped again before this function returns).
+fn run_allocation_pattern<T>(pattern: &str, count: usize, create: impl Fn() -> T) -> (f64, f64) {
+    let mut alloc_ms = 0.0;
+    let mut dealloc_ms = 0.0;
+
+    match pattern {
+        "lifo" => {
+            let mut items = Vec::with_capacity(count);
+            let start = Instant::now();
+            for _ in 0..count {
+                items.push(create());
+            }
+            alloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+
+            let start = Instant::now();
+            while let Some(item) = items.pop() {
+                drop(item);
+            }
+            dealloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+        }
+        "interleaved" => {
+            // Keep at most two items alive at a time, so every allocation is
+            // immediately followed by freeing the previous one, stressing
+            // allocator slot reuse rather than net memory growth.
+            let mut active: Vec<T> = Vec::new();
+            for _ in 0..count {
+                let start = Instant::now();
+                active.push(create());
+                alloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+
+                if active.len() >= 2 {
+                    let start = Instant::now();
+                    let item = active.remove(0);
+                    drop(item);
+                    dealloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+                }
+            }
+            let start = Instant::now();
+            active.clear();
+            dealloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+        }
+        "fragmented" => {
+            // Free every other item to punch holes, then allocate count/2
+            // more to see whether the allocator reuses them.
+            let start = Instant::now();
+            let mut items: Vec<Option<T>> = (0..count).map(|_| Some(create())).collect();
+            alloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+
+            let start = Instant::now();
+            for i in (1..count).step_by(2) {
+                items[i] = None;
+            }
+            dealloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+
+            let start = Instant::now();
+            for _ in 0..(count / 2) {
+                items.push(Some(create()));
+            }
+            alloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+
+            let start = Instant::now();
+            items.clear();
+            dealloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+        }
+        _ => {
+            // "sequential" (and any unrecognized pattern): allocate
+            // everything, then free it all in order.
+            let mut items = Vec::with_capacity(count);
+            let start = Instant::now();
+            for _ in 0..count {
+                items.push(create());
+            }
+            alloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+
+            let start = Instant::now();
+            for item in items.drain(..) {
+                drop(item);
+            }
+            dealloc_ms += start.elapsed().as_secs_f64() * 1000.0;
+        }
+    }
+
+    (alloc_ms, dealloc_ms)
+}
+
+fn empty_stats() -> DistributionStats {
+    DistributionStats {
+        min: 0.0,
+        max: 0.0,
+        median: 0.0,
+        p95: 0.0,
+        p99: 0.0,
+        stddev: 0.0,
+        rejected_outliers: 0,
+    }
 }
 
 fn run_memory_allocation_benchmark(params: Parameters) -> Results {
@@ -156,17 +473,28 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
         avg_allocation_time: 0.0,
         avg_deallocation_time: 0.0,
         avg_memory_efficiency: 0.0,
+        allocation_time_stats: empty_stats(),
+        deallocation_time_stats: empty_stats(),
     };
-    
+
+    let warmup_iterations = params.warmup_iterations.unwrap_or(0);
     let mut all_allocation_times = Vec::new();
     let mut all_deallocation_times = Vec::new();
     let mut all_memory_efficiencies = Vec::new();
-    
+
     for &size in &params.allocation_sizes {
         for &count in &params.allocation_counts {
             for structure in &params.data_structures {
                 for pattern in &params.allocation_patterns {
-                    eprintln!("Testing {} allocation: size={}, count={}, pattern={}...", 
+                    // grid_flood_fill times repeated flood-fill scans over one grid, not
+                    // per-item allocate/free via `run_allocation_pattern`, so
+                    // `allocation_patterns` doesn't apply to it. Run it once
+                    // rather than repeating identical work under every pattern.
+                    if structure == "grid_flood_fill" && pattern != &params.allocation_patterns[0] {
+                        continue;
+                    }
+
+                    eprintln!("Testing {} allocation: size={}, count={}, pattern={}...",
                              structure, size, count, pattern);
                     
                     let mut test_case = TestCase {
@@ -178,6 +506,8 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                         avg_allocation_time: 0.0,
                         avg_deallocation_time: 0.0,
                         avg_memory_efficiency: 0.0,
+                        allocation_time_stats: empty_stats(),
+                        deallocation_time_stats: empty_stats(),
                     };
                     
                     let mut allocation_times = Vec::new();
@@ -188,6 +518,7 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                         eprintln!("  Iteration {}/{}...", i + 1, params.iterations);
                         
                         let initial_memory = get_memory_usage();
+                        let _ = get_peak_memory(); // reset the high-water mark to this iteration's baseline
                         summary.total_tests += 1;
                         
                         let mut iteration_result = IterationResult {
@@ -213,11 +544,10 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                         
                         let success = match structure.as_str() {
                             "array" => {
-                                let start = Instant::now();
-                                let _allocated = allocate_arrays(size, count);
-                                let allocation_time = start.elapsed().as_secs_f64() * 1000.0;
-                                
-                                let peak_memory = get_memory_usage();
+                                let (allocation_time, deallocation_time) =
+                                    run_allocation_pattern(pattern, count, || create_array_item(size));
+
+                                let peak_memory = get_peak_memory();
                                 let memory_used = peak_memory.saturating_sub(initial_memory);
                                 let theoretical_size = size * count * std::mem::size_of::<i32>();
                                 let memory_efficiency = if memory_used > 0 {
@@ -225,12 +555,10 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                 } else {
                                     100.0
                                 };
-                                
+
                                 allocation_times.push(allocation_time);
-                                all_allocation_times.push(allocation_time);
                                 memory_efficiencies.push(memory_efficiency);
-                                all_memory_efficiencies.push(memory_efficiency);
-                                
+
                                 iteration_result.allocation = AllocationResult {
                                     success: true,
                                     time_ms: allocation_time,
@@ -240,16 +568,11 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                     items_allocated: count,
                                     error: None,
                                 };
-                                
-                                // Deallocation (drop happens automatically)
-                                let start = Instant::now();
-                                drop(_allocated);
-                                let deallocation_time = start.elapsed().as_secs_f64() * 1000.0;
+
                                 let final_memory = get_memory_usage();
-                                
+
                                 deallocation_times.push(deallocation_time);
-                                all_deallocation_times.push(deallocation_time);
-                                
+
                                 iteration_result.deallocation = DeallocationResult {
                                     success: true,
                                     time_ms: deallocation_time,
@@ -257,15 +580,14 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                     memory_freed: peak_memory.saturating_sub(final_memory),
                                     error: None,
                                 };
-                                
+
                                 true
                             },
                             "hash_map" => {
-                                let start = Instant::now();
-                                let _allocated = allocate_hash_maps(size, count);
-                                let allocation_time = start.elapsed().as_secs_f64() * 1000.0;
-                                
-                                let peak_memory = get_memory_usage();
+                                let (allocation_time, deallocation_time) =
+                                    run_allocation_pattern(pattern, count, || create_hash_map_item(size));
+
+                                let peak_memory = get_peak_memory();
                                 let memory_used = peak_memory.saturating_sub(initial_memory);
                                 let theoretical_size = size * count * (std::mem::size_of::<i32>() * 2);
                                 let memory_efficiency = if memory_used > 0 {
@@ -273,12 +595,10 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                 } else {
                                     100.0
                                 };
-                                
+
                                 allocation_times.push(allocation_time);
-                                all_allocation_times.push(allocation_time);
                                 memory_efficiencies.push(memory_efficiency);
-                                all_memory_efficiencies.push(memory_efficiency);
-                                
+
                                 iteration_result.allocation = AllocationResult {
                                     success: true,
                                     time_ms: allocation_time,
@@ -288,15 +608,11 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                     items_allocated: count,
                                     error: None,
                                 };
-                                
-                                let start = Instant::now();
-                                drop(_allocated);
-                                let deallocation_time = start.elapsed().as_secs_f64() * 1000.0;
+
                                 let final_memory = get_memory_usage();
-                                
+
                                 deallocation_times.push(deallocation_time);
-                                all_deallocation_times.push(deallocation_time);
-                                
+
                                 iteration_result.deallocation = DeallocationResult {
                                     success: true,
                                     time_ms: deallocation_time,
@@ -304,15 +620,14 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                     memory_freed: peak_memory.saturating_sub(final_memory),
                                     error: None,
                                 };
-                                
+
                                 true
                             },
                             "linked_list" => {
-                                let start = Instant::now();
-                                let _allocated = allocate_linked_lists(size, count);
-                                let allocation_time = start.elapsed().as_secs_f64() * 1000.0;
-                                
-                                let peak_memory = get_memory_usage();
+                                let (allocation_time, deallocation_time) =
+                                    run_allocation_pattern(pattern, count, || create_linked_list_item(size));
+
+                                let peak_memory = get_peak_memory();
                                 let memory_used = peak_memory.saturating_sub(initial_memory);
                                 let theoretical_size = size * count * (std::mem::size_of::<i32>() + std::mem::size_of::<usize>());
                                 let memory_efficiency = if memory_used > 0 {
@@ -320,12 +635,10 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                 } else {
                                     100.0
                                 };
-                                
+
                                 allocation_times.push(allocation_time);
-                                all_allocation_times.push(allocation_time);
                                 memory_efficiencies.push(memory_efficiency);
-                                all_memory_efficiencies.push(memory_efficiency);
-                                
+
                                 iteration_result.allocation = AllocationResult {
                                     success: true,
                                     time_ms: allocation_time,
@@ -335,15 +648,59 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                     items_allocated: count,
                                     error: None,
                                 };
-                                
+
+                                let final_memory = get_memory_usage();
+
+                                deallocation_times.push(deallocation_time);
+
+                                iteration_result.deallocation = DeallocationResult {
+                                    success: true,
+                                    time_ms: deallocation_time,
+                                    final_memory,
+                                    memory_freed: peak_memory.saturating_sub(final_memory),
+                                    error: None,
+                                };
+
+                                true
+                            },
+                            "grid_flood_fill" => {
                                 let start = Instant::now();
-                                drop(_allocated);
+                                let grid = create_grid(size);
+                                let mut clusters_found = 0usize;
+                                for _ in 0..count {
+                                    clusters_found += flood_fill_clusters(&grid, size);
+                                }
+                                let allocation_time = start.elapsed().as_secs_f64() * 1000.0;
+
+                                let peak_memory = get_peak_memory();
+                                let memory_used = peak_memory.saturating_sub(initial_memory);
+                                let theoretical_size = size * size * std::mem::size_of::<u8>();
+                                let memory_efficiency = if memory_used > 0 {
+                                    (theoretical_size as f64 / memory_used as f64) * 100.0
+                                } else {
+                                    100.0
+                                };
+
+                                allocation_times.push(allocation_time);
+                                memory_efficiencies.push(memory_efficiency);
+
+                                iteration_result.allocation = AllocationResult {
+                                    success: true,
+                                    time_ms: allocation_time,
+                                    memory_used,
+                                    peak_memory,
+                                    memory_efficiency,
+                                    items_allocated: clusters_found,
+                                    error: None,
+                                };
+
+                                let start = Instant::now();
+                                drop(grid);
                                 let deallocation_time = start.elapsed().as_secs_f64() * 1000.0;
                                 let final_memory = get_memory_usage();
-                                
+
                                 deallocation_times.push(deallocation_time);
-                                all_deallocation_times.push(deallocation_time);
-                                
+
                                 iteration_result.deallocation = DeallocationResult {
                                     success: true,
                                     time_ms: deallocation_time,
@@ -351,7 +708,7 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                                     memory_freed: peak_memory.saturating_sub(final_memory),
                                     error: None,
                                 };
-                                
+
                                 true
                             },
                             _ => {
@@ -368,35 +725,46 @@ fn run_memory_allocation_benchmark(params: Parameters) -> Results {
                         
                         test_case.iterations.push(iteration_result);
                     }
-                    
-                    // Calculate averages
-                    if !allocation_times.is_empty() {
-                        test_case.avg_allocation_time = allocation_times.iter().sum::<f64>() / allocation_times.len() as f64;
-                    }
-                    if !deallocation_times.is_empty() {
-                        test_case.avg_deallocation_time = deallocation_times.iter().sum::<f64>() / deallocation_times.len() as f64;
-                    }
-                    if !memory_efficiencies.is_empty() {
-                        test_case.avg_memory_efficiency = memory_efficiencies.iter().sum::<f64>() / memory_efficiencies.len() as f64;
+
+                    // Discard warmup samples, then reject outliers and compute distribution
+                    // stats over whatever's left.
+                    let warmup = warmup_iterations.min(allocation_times.len());
+                    let post_warmup_alloc = &allocation_times[warmup..];
+                    let post_warmup_dealloc = &deallocation_times[warmup.min(deallocation_times.len())..];
+                    let post_warmup_mem_eff = &memory_efficiencies[warmup.min(memory_efficiencies.len())..];
+
+                    all_allocation_times.extend_from_slice(post_warmup_alloc);
+                    all_deallocation_times.extend_from_slice(post_warmup_dealloc);
+                    all_memory_efficiencies.extend_from_slice(post_warmup_mem_eff);
+
+                    let (allocation_time_stats, avg_allocation_time) = compute_stats(post_warmup_alloc);
+                    let (deallocation_time_stats, avg_deallocation_time) = compute_stats(post_warmup_dealloc);
+                    test_case.avg_allocation_time = avg_allocation_time;
+                    test_case.avg_deallocation_time = avg_deallocation_time;
+                    test_case.allocation_time_stats = allocation_time_stats;
+                    test_case.deallocation_time_stats = deallocation_time_stats;
+                    if !post_warmup_mem_eff.is_empty() {
+                        test_case.avg_memory_efficiency =
+                            post_warmup_mem_eff.iter().sum::<f64>() / post_warmup_mem_eff.len() as f64;
                     }
-                    
+
                     test_cases.push(test_case);
                 }
             }
         }
     }
-    
-    // Calculate overall summary
-    if !all_allocation_times.is_empty() {
-        summary.avg_allocation_time = all_allocation_times.iter().sum::<f64>() / all_allocation_times.len() as f64;
-    }
-    if !all_deallocation_times.is_empty() {
-        summary.avg_deallocation_time = all_deallocation_times.iter().sum::<f64>() / all_deallocation_times.len() as f64;
-    }
+
+    // Calculate overall summary (warmup samples were already excluded per test case)
+    let (overall_allocation_stats, overall_avg_allocation_time) = compute_stats(&all_allocation_times);
+    let (overall_deallocation_stats, overall_avg_deallocation_time) = compute_stats(&all_deallocation_times);
+    summary.avg_allocation_time = overall_avg_allocation_time;
+    summary.avg_deallocation_time = overall_avg_deallocation_time;
+    summary.allocation_time_stats = overall_allocation_stats;
+    summary.deallocation_time_stats = overall_deallocation_stats;
     if !all_memory_efficiencies.is_empty() {
         summary.avg_memory_efficiency = all_memory_efficiencies.iter().sum::<f64>() / all_memory_efficiencies.len() as f64;
     }
-    
+
     let end_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs_f64();
     
     Results {